@@ -10,7 +10,7 @@ use gorm::{
         DeleteStatementReturning, ExecuteSqlStatment, Filter, FilterDeleteStatement, GroupBy,
         InnerJoinOnTrait, InnerJoinTrait, InsertStatementOnConflict, InsertStatementReturning,
         LoadSingleColumnSqlStatment, LoadSqlStatment, OrderBy, OrderBySelectedValue, SelectFrom,
-        SelectValues, UpdateStatementReturning,
+        SelectValues, UpdateStatementAllRows, UpdateStatementReturning,
     },
     update_set, FromQueryResult,
 };
@@ -249,6 +249,7 @@ async fn main() -> anyhow::Result<()> {
     let ages = person::table
         .update()
         .set(update_set!(person::age = person::age.add(1)))
+        .all_rows()
         .returning(person::age)
         .load_all_values(&pool)
         .await?;
@@ -1,5 +1,6 @@
 mod from_query_result;
 mod migration;
+mod raw_sql;
 mod select_values;
 mod selected_value_to_order_by;
 mod sql_enum;
@@ -193,6 +194,12 @@ pub fn create_field_name_cons_list(item: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// The `id` field is the primary key of the table. It can be of type `i16`,
+/// `i32` or `i64`, in which case it is created as a serial column, or of type
+/// `uuid::Uuid` if the `uuid` feature flag is enabled, in which case it is
+/// generated using `gen_random_uuid()`. In both cases, rows inserted without an
+/// id are assigned one by the database.
+///
 /// The `Table` macro, besides implementing the `Table` trait for the provided
 /// struct, will also create a module which has the same name as the struct, but
 /// converted to `snake_case`. This module will contain useful items which allow
@@ -367,13 +374,15 @@ pub fn selected_value_to_order_by(input_tokens: TokenStream) -> TokenStream {
 /// sql update statement.
 ///
 /// The input to this macro should be a comma seperated list of assignments of
-/// sql expressions to columns, for example:
+/// sql expressions to columns. The assigned expressions may also reference
+/// columns of tables added to the update statement using its `from` function.
 ///
 /// # Example
 /// ```rust
 /// let new_ages = person::table
 ///     .update()
 ///     .set(update_set!(person::age = person::age.add(1)))
+///     .all_rows()
 ///     .returning(person::age)
 ///     .load_all_values(...)
 ///     .await?;
@@ -401,3 +410,65 @@ pub fn update_set(input_tokens: TokenStream) -> TokenStream {
 pub fn sql_enum(input_tokens: TokenStream) -> TokenStream {
     sql_enum::sql_enum(input_tokens)
 }
+
+/// Creates an sql expression from a raw sql string. This can be used as an
+/// escape hatch for sql features which are not supported by the orm.
+///
+/// The first argument to this macro is the sql type of the expression, which
+/// is followed by the raw sql string and the parameters to bind to it. Each
+/// `{}` placeholder in the raw sql string is replaced with a bound parameter,
+/// in the order in which the parameters were provided. Literal braces can be
+/// written as `{{` and `}}`.
+///
+/// Please note that the orm can't verify the raw sql, so it is up to you to
+/// make sure that it is valid and that it evaluates to the specified sql type.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(Table)]
+/// struct Person {
+///     id: i32,
+///     name: String,
+/// }
+///
+/// let _ = person::table
+///     .find()
+///     .filter(sql!(SqlBool, "\"person\".\"name\" % {}", "James"))
+///     .load_all::<Person>(...)
+///     .await?;
+/// ```
+#[proc_macro]
+pub fn sql(input_tokens: TokenStream) -> TokenStream {
+    raw_sql::sql(input_tokens)
+}
+
+/// Creates an sql statement from a raw sql string.
+///
+/// The raw sql string may optionally be preceded by a parenthesized list of the
+/// statement's output fields and their types, followed by a `=>`. The declared
+/// output fields are checked against the fields of the struct that the results
+/// are loaded into, just like for any other statement. The raw sql string is
+/// followed by the parameters to bind to it, which are specified using `{}`
+/// placeholders, just like in the [`sql!`] macro.
+///
+/// # Example
+/// ```rust,ignore
+/// #[derive(FromQueryResult)]
+/// struct NameAndAge {
+///     name: String,
+///     age: i32,
+/// }
+///
+/// let _ = sql_statement!(
+///     (name: String, age: i32) => "SELECT name, age FROM person WHERE age > {}",
+///     18
+/// )
+/// .load_all::<NameAndAge>(...)
+/// .await?;
+///
+/// sql_statement!("VACUUM person").execute(...).await?;
+/// ```
+#[proc_macro]
+pub fn sql_statement(input_tokens: TokenStream) -> TokenStream {
+    raw_sql::sql_statement(input_tokens)
+}
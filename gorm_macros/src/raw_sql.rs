@@ -0,0 +1,336 @@
+use proc_macro::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{
+    parenthesized, parse::Parse, parse_macro_input, punctuated::Punctuated, Expr, LitStr, Token,
+    Type,
+};
+
+use crate::util::generate_fields_cons_list_type;
+
+pub fn sql(input_tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input_tokens as RawSqlExpressionInput);
+
+    let raw_sql = match RawSql::parse(&input.raw_sql, &input.parameters) {
+        Ok(raw_sql) => raw_sql,
+        Err(err) => return err,
+    };
+
+    let sql_type = &input.sql_type;
+    let parameter_generics_definition = raw_sql.parameter_generics_definition();
+    let parameter_generics_definition_ref = &parameter_generics_definition;
+    let parameter_generics = raw_sql.parameter_generics();
+    let parameter_generics_ref = &parameter_generics;
+    let struct_fields_definition = raw_sql.struct_fields_definition();
+    let initialize_struct_fields = raw_sql.initialize_struct_fields();
+    let write_raw_sql = raw_sql.write_raw_sql();
+
+    quote! {
+        {
+            struct RawSqlExpression<#parameter_generics_definition_ref> {
+                #struct_fields_definition
+            }
+
+            #[automatically_derived]
+            impl<
+                S: ::gorm::sql::SelectableTables,
+                #parameter_generics_definition_ref
+            > ::gorm::sql::SqlExpression<S> for RawSqlExpression<#parameter_generics_ref>
+            {
+                type SqlType = #sql_type;
+                type RustType = <#sql_type as ::gorm::sql::SqlType>::RustType;
+
+                const IS_AGGREGATE: bool = false;
+
+                fn write_sql_string<'s, 'a>(
+                    &'s self,
+                    f: &mut ::std::string::String,
+                    parameter_binder: &mut ::gorm::sql::ParameterBinder<'a>,
+                ) -> ::std::fmt::Result
+                where
+                    's: 'a
+                {
+                    use ::std::fmt::Write;
+
+                    // the raw sql is parenthesized so that it is evaluated as
+                    // a single expression when combined with other operators.
+                    ::std::write!(f, "(")?;
+                    #write_raw_sql
+                    ::std::write!(f, ")")
+                }
+            }
+
+            RawSqlExpression { #initialize_struct_fields }
+        }
+    }
+    .into()
+}
+
+pub fn sql_statement(input_tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input_tokens as RawSqlStatementInput);
+
+    let raw_sql = match RawSql::parse(&input.raw_sql, &input.parameters) {
+        Ok(raw_sql) => raw_sql,
+        Err(err) => return err,
+    };
+
+    let output_fields_type = generate_fields_cons_list_type(
+        input
+            .output_fields
+            .iter()
+            .map(|output_field| (&output_field.name, &output_field.ty)),
+    );
+    let parameter_generics_definition = raw_sql.parameter_generics_definition();
+    let parameter_generics_definition_ref = &parameter_generics_definition;
+    let parameter_generics = raw_sql.parameter_generics();
+    let struct_fields_definition = raw_sql.struct_fields_definition();
+    let initialize_struct_fields = raw_sql.initialize_struct_fields();
+    let write_raw_sql = raw_sql.write_raw_sql();
+
+    quote! {
+        {
+            struct RawSqlStatement<#parameter_generics_definition_ref> {
+                #struct_fields_definition
+            }
+
+            #[automatically_derived]
+            impl<#parameter_generics_definition_ref> ::gorm::statements::SqlStatement
+                for RawSqlStatement<#parameter_generics>
+            {
+                type OutputFields = #output_fields_type;
+
+                fn write_sql_string<'s, 'a>(
+                    &'s self,
+                    f: &mut ::std::string::String,
+                    parameter_binder: &mut ::gorm::sql::ParameterBinder<'a>,
+                ) -> ::std::fmt::Result
+                where
+                    's: 'a
+                {
+                    use ::std::fmt::Write;
+
+                    #write_raw_sql
+                    Ok(())
+                }
+            }
+
+            #[automatically_derived]
+            impl<#parameter_generics_definition_ref> ::std::fmt::Display
+                for RawSqlStatement<#parameter_generics>
+            {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.write_str(&::gorm::statements::SqlStatement::to_debug_sql(self))
+                }
+            }
+
+            #[automatically_derived]
+            impl<#parameter_generics_definition_ref> ::std::fmt::Debug
+                for RawSqlStatement<#parameter_generics>
+            {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    ::std::fmt::Display::fmt(self, f)
+                }
+            }
+
+            RawSqlStatement { #initialize_struct_fields }
+        }
+    }
+    .into()
+}
+
+/// A raw sql string which was split into the parts between its parameter
+/// placeholders.
+struct RawSql<'a> {
+    /// The parts of the raw sql string, without the parameter placeholders.
+    /// There is always exactly one more part than there are parameters.
+    parts: Vec<String>,
+    parameters: &'a Punctuated<Expr, Token![,]>,
+}
+impl<'a> RawSql<'a> {
+    /// Splits the given raw sql string into parts at each `{}` placeholder,
+    /// and makes sure that the amount of placeholders matches the amount of
+    /// provided parameters. Literal braces can be written as `{{` and `}}`.
+    fn parse(
+        raw_sql: &LitStr,
+        parameters: &'a Punctuated<Expr, Token![,]>,
+    ) -> Result<Self, TokenStream> {
+        let raw_sql_string = raw_sql.value();
+        let mut parts = vec![String::new()];
+        let mut chars = raw_sql_string.chars().peekable();
+
+        while let Some(chr) = chars.next() {
+            match (chr, chars.peek()) {
+                ('{', Some('{')) | ('}', Some('}')) => {
+                    chars.next();
+                    parts.last_mut().unwrap().push(chr);
+                },
+                ('{', Some('}')) => {
+                    chars.next();
+                    parts.push(String::new());
+                },
+                ('{', _) | ('}', _) => {
+                    return Err(quote_spanned! {
+                        raw_sql.span() => compile_error!("invalid brace in raw sql, parameters are specified using `{}`, and literal braces can be written as `{{` and `}}`");
+                    }
+                    .into())
+                },
+                _ => parts.last_mut().unwrap().push(chr),
+            }
+        }
+
+        let placeholders_amount = parts.len() - 1;
+        if placeholders_amount != parameters.len() {
+            let error_message = format!(
+                "the raw sql contains {} parameter placeholders but {} parameters were provided",
+                placeholders_amount,
+                parameters.len()
+            );
+            return Err(quote_spanned! {
+                raw_sql.span() => compile_error!(#error_message);
+            }
+            .into());
+        }
+
+        Ok(Self { parts, parameters })
+    }
+
+    fn parameter_generic_idents(&self) -> impl Iterator<Item = proc_macro2::Ident> {
+        (0..self.parameters.len())
+            .map(|i| proc_macro2::Ident::new(&format!("P{}", i), proc_macro2::Span::call_site()))
+    }
+
+    fn parameter_field_idents(&self) -> impl Iterator<Item = proc_macro2::Ident> {
+        (0..self.parameters.len())
+            .map(|i| proc_macro2::Ident::new(&format!("p{}", i), proc_macro2::Span::call_site()))
+    }
+
+    /// The definition of the generics of the parameters, for example:
+    /// `P0: ToSql + Sync, P1: ToSql + Sync`
+    fn parameter_generics_definition(&self) -> proc_macro2::TokenStream {
+        let parameter_generic_idents = self.parameter_generic_idents();
+        quote! {
+            #(#parameter_generic_idents: ::gorm::tokio_postgres::types::ToSql + ::std::marker::Sync),*
+        }
+    }
+
+    /// The generics of the parameters, for example: `P0, P1`
+    fn parameter_generics(&self) -> proc_macro2::TokenStream {
+        let parameter_generic_idents = self.parameter_generic_idents();
+        quote! {
+            #(#parameter_generic_idents),*
+        }
+    }
+
+    fn struct_fields_definition(&self) -> proc_macro2::TokenStream {
+        let parameter_field_idents = self.parameter_field_idents();
+        let parameter_generic_idents = self.parameter_generic_idents();
+        quote! {
+            #(#parameter_field_idents: #parameter_generic_idents),*
+        }
+    }
+
+    fn initialize_struct_fields(&self) -> proc_macro2::TokenStream {
+        let parameter_field_idents = self.parameter_field_idents();
+        let parameters = self.parameters.iter();
+        quote! {
+            #(#parameter_field_idents: #parameters),*
+        }
+    }
+
+    /// Writes the raw sql string, binding each parameter in place of its
+    /// placeholder.
+    fn write_raw_sql(&self) -> proc_macro2::TokenStream {
+        let first_part = &self.parts[0];
+        let write_each_parameter_and_part = self
+            .parameter_field_idents()
+            .zip(&self.parts[1..])
+            .map(|(parameter_field_ident, part)| {
+                quote! {
+                    ::std::write!(f, "{}", parameter_binder.bind_parameter(&self.#parameter_field_ident))?;
+                    f.push_str(#part);
+                }
+            });
+        quote! {
+            f.push_str(#first_part);
+            #(#write_each_parameter_and_part)*
+        }
+    }
+}
+
+/// The parsed input of the `sql!` macro
+struct RawSqlExpressionInput {
+    sql_type: Type,
+    raw_sql: LitStr,
+    parameters: Punctuated<Expr, Token![,]>,
+}
+
+impl Parse for RawSqlExpressionInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let sql_type = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let raw_sql = input.parse()?;
+        let parameters = parse_parameters(input)?;
+        Ok(Self {
+            sql_type,
+            raw_sql,
+            parameters,
+        })
+    }
+}
+
+/// A single output field declared in the input of the `sql_statement!` macro.
+struct RawSqlStatementOutputField {
+    name: Option<proc_macro2::Ident>,
+    ty: Type,
+}
+
+impl Parse for RawSqlStatementOutputField {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+        Ok(Self {
+            name: Some(name),
+            ty,
+        })
+    }
+}
+
+/// The parsed input of the `sql_statement!` macro
+struct RawSqlStatementInput {
+    output_fields: Punctuated<RawSqlStatementOutputField, Token![,]>,
+    raw_sql: LitStr,
+    parameters: Punctuated<Expr, Token![,]>,
+}
+
+impl Parse for RawSqlStatementInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        // the output fields are optional, statements without output fields
+        // can only be executed.
+        let output_fields = if input.peek(syn::token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            let output_fields = Punctuated::parse_terminated(&content)?;
+            input.parse::<Token![=>]>()?;
+            output_fields
+        } else {
+            Punctuated::new()
+        };
+        let raw_sql = input.parse()?;
+        let parameters = parse_parameters(input)?;
+        Ok(Self {
+            output_fields,
+            raw_sql,
+            parameters,
+        })
+    }
+}
+
+/// Parses the comma seperated list of parameters which follows the raw sql
+/// string.
+fn parse_parameters(input: syn::parse::ParseStream) -> syn::Result<Punctuated<Expr, Token![,]>> {
+    if input.is_empty() {
+        return Ok(Punctuated::new());
+    }
+    input.parse::<Token![,]>()?;
+    Punctuated::parse_terminated(input)
+}
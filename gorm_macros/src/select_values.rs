@@ -62,7 +62,7 @@ pub fn select_values(input_tokens: TokenStream) -> TokenStream {
         let generic_name =
             proc_macro2::Ident::new(&format!("E{}", i), proc_macro2::Span::call_site());
         quote! {
-            #generic_name: ::gorm::sql::SqlExpression<S>
+            #generic_name: ::gorm::sql::SelectedValue<S>
         }
     });
 
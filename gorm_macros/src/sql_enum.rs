@@ -137,7 +137,7 @@ pub fn sql_enum(input_tokens: TokenStream) -> TokenStream {
         #[automatically_derived]
         impl<S: ::gorm::sql::SelectableTables> ::gorm::sql::SqlExpression<S> for #enum_ident
         {
-            type RustType = #integer_type;
+            type RustType = Self;
             type SqlType = <#integer_type as ::gorm::sql::IntoSqlType>::SqlType;
 
             const IS_AGGREGATE: bool = false;
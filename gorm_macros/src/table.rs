@@ -90,6 +90,12 @@ pub fn table(input_tokens: TokenStream) -> TokenStream {
         .map(|field| field.generate_table_field_struct());
     let fields_type =
         generate_fields_cons_list_type(fields.iter().map(|field| (&field.ident, &field.ty)));
+    let fields_without_id_type = generate_fields_cons_list_type(
+        fields
+            .iter()
+            .filter(|field| field.ident.as_ref().unwrap() != "id")
+            .map(|field| (&field.ident, &field.ty)),
+    );
 
     let table_name_ident = proc_macro2::Ident::new(&table_name, table_struct_ident.span());
 
@@ -170,6 +176,7 @@ pub fn table(input_tokens: TokenStream) -> TokenStream {
         #[automatically_derived]
         impl ::gorm::sql::Table for #table_struct_ident {
             type Fields = #fields_type;
+            type FieldsWithoutId = #fields_without_id_type;
             const FIELDS: &'static [::gorm::sql::TableField] = &[
                 #( #table_field_structs ),*
             ];
@@ -21,10 +21,14 @@ pub fn update_set(input_tokens: TokenStream) -> TokenStream {
     let expr_generic_name_idents_ref = expr_generic_name_idents.as_slice();
 
     let expr_generics_definition = quote! {
-        #(#expr_generic_name_idents_ref: ::gorm::sql::SqlExpression<T>),*
+        #(#expr_generic_name_idents_ref),*
     };
     let expr_generics_definition_ref = &expr_generics_definition;
 
+    let expr_generics_constraints = quote! {
+        #(#expr_generic_name_idents_ref: ::gorm::sql::SqlExpression<S>),*
+    };
+
     let column_generic_name_idents: Vec<_> = (0..input.assignments.len())
         .map(|i| {
             let generic_name_string = format!("C{}", i);
@@ -104,12 +108,16 @@ pub fn update_set(input_tokens: TokenStream) -> TokenStream {
             impl<
                 #column_generics_definition_ref,
                 #expr_generics_definition_ref,
-                T: ::gorm::sql::Table
-            > ::gorm::sql::UpdateSet for CustomUpdateSet<
+                T: ::gorm::sql::Table,
+                S: ::gorm::sql::SelectableTables
+            > ::gorm::sql::UpdateSet<S> for CustomUpdateSet<
                 #(#column_generic_name_idents_ref),* ,
                 #(#expr_generic_name_idents_ref),* ,
                 T
-            > {
+            >
+            where
+                #expr_generics_constraints
+            {
                 type UpdateTable = T;
 
                 fn write_sql_string<'s, 'a>(
@@ -36,6 +36,9 @@ pub enum Error {
         enum_name: &'static str,
         integer_string: String,
     },
+
+    #[error("failed to parse query plan: {0}")]
+    FailedToParseQueryPlan(#[source] serde_json::Error),
 }
 
 /// A result type with [`enum@Error`] as its error type.
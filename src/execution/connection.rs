@@ -3,7 +3,7 @@ use deadpool_postgres::tokio_postgres::{Client, NoTls};
 use super::transaction::DatabaseTransaction;
 use crate::{
     error::*,
-    execution::{impl_sql_statement_executor, ExecuteResult},
+    execution::{impl_sql_statement_executor, ExecuteResult, StatementStatisticsCollector},
     sql::FromQueryResult,
     statements::SqlStatement,
 };
@@ -44,4 +44,10 @@ async fn get_raw_executor(database_connection: &DatabaseConnection) -> Result<&C
     Ok(&database_connection.client)
 }
 
-impl_sql_statement_executor! {DatabaseConnection, get_raw_executor}
+fn get_statistics_collector(
+    _database_connection: &DatabaseConnection,
+) -> Option<&StatementStatisticsCollector> {
+    None
+}
+
+impl_sql_statement_executor! {DatabaseConnection, get_raw_executor, get_statistics_collector}
@@ -1,9 +1,11 @@
+use std::sync::Arc;
+
 use deadpool_postgres::Object;
 
 use super::transaction::DatabaseTransactionFromPool;
 use crate::{
     error::*,
-    execution::{impl_sql_statement_executor, ExecuteResult},
+    execution::{impl_sql_statement_executor, ExecuteResult, StatementStatisticsCollector},
     statements::SqlStatement,
     FromQueryResult,
 };
@@ -11,6 +13,7 @@ use crate::{
 /// A database connection from a connection pool.
 pub struct DatabaseConnectionFromPool {
     pub(super) client: Object,
+    pub(super) statistics_collector: Option<Arc<StatementStatisticsCollector>>,
 }
 
 impl DatabaseConnectionFromPool {
@@ -21,6 +24,7 @@ impl DatabaseConnectionFromPool {
     pub async fn begin_transaction(&mut self) -> Result<DatabaseTransactionFromPool> {
         Ok(DatabaseTransactionFromPool {
             transaction: self.client.transaction().await?,
+            statistics_collector: self.statistics_collector.clone(),
         })
     }
 }
@@ -29,4 +33,10 @@ async fn get_raw_executor(database_connection: &DatabaseConnectionFromPool) -> R
     Ok(&database_connection.client)
 }
 
-impl_sql_statement_executor! {DatabaseConnectionFromPool, get_raw_executor}
+fn get_statistics_collector(
+    database_connection: &DatabaseConnectionFromPool,
+) -> Option<&StatementStatisticsCollector> {
+    database_connection.statistics_collector.as_deref()
+}
+
+impl_sql_statement_executor! {DatabaseConnectionFromPool, get_raw_executor, get_statistics_collector}
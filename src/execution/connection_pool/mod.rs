@@ -2,12 +2,14 @@ mod connection;
 mod transaction;
 
 pub use connection::*;
+use std::sync::Arc;
+
 use deadpool_postgres::{tokio_postgres::NoTls, Manager, ManagerConfig, Object, Pool};
 pub use transaction::*;
 
 use crate::{
     error::*,
-    execution::{impl_sql_statement_executor, ExecuteResult},
+    execution::{impl_sql_statement_executor, ExecuteResult, StatementStatisticsCollector},
     sql::FromQueryResult,
     statements::SqlStatement,
 };
@@ -18,6 +20,7 @@ use crate::{
 /// the pool using the [`DatabaseConnectionPool::get`] function.
 pub struct DatabaseConnectionPool {
     pool: Pool,
+    statistics_collector: Option<Arc<StatementStatisticsCollector>>,
 }
 
 impl DatabaseConnectionPool {
@@ -34,13 +37,34 @@ impl DatabaseConnectionPool {
         );
         let pool = Pool::builder(manager).build()?;
 
-        Ok(Self { pool })
+        Ok(Self {
+            pool,
+            statistics_collector: None,
+        })
+    }
+
+    /// Attaches the given statistics collector to this connection pool.
+    ///
+    /// The collector will record each statement executed on the pool, or on
+    /// connections and transactions taken from it.
+    pub fn with_statistics(mut self, statistics_collector: StatementStatisticsCollector) -> Self {
+        self.statistics_collector = Some(Arc::new(statistics_collector));
+        self
+    }
+
+    /// Returns the statistics collector attached to this connection pool, if
+    /// any.
+    pub fn statistics(&self) -> Option<&StatementStatisticsCollector> {
+        self.statistics_collector.as_deref()
     }
 
     /// Returns a single connection from the connection pool.
     pub async fn get(&self) -> Result<DatabaseConnectionFromPool> {
         let client = self.pool.get().await?;
-        Ok(DatabaseConnectionFromPool { client })
+        Ok(DatabaseConnectionFromPool {
+            client,
+            statistics_collector: self.statistics_collector.clone(),
+        })
     }
 }
 
@@ -48,4 +72,10 @@ async fn get_raw_executor(database_connection_pool: &DatabaseConnectionPool) ->
     Ok(database_connection_pool.pool.get().await?)
 }
 
-impl_sql_statement_executor! {DatabaseConnectionPool, get_raw_executor}
+fn get_statistics_collector(
+    database_connection_pool: &DatabaseConnectionPool,
+) -> Option<&StatementStatisticsCollector> {
+    database_connection_pool.statistics()
+}
+
+impl_sql_statement_executor! {DatabaseConnectionPool, get_raw_executor, get_statistics_collector}
@@ -1,8 +1,10 @@
+use std::sync::Arc;
+
 use deadpool_postgres::Transaction;
 
 use crate::{
     error::*,
-    execution::{impl_sql_statement_executor, ExecuteResult},
+    execution::{impl_sql_statement_executor, ExecuteResult, StatementStatisticsCollector},
     sql::FromQueryResult,
     statements::SqlStatement,
 };
@@ -16,6 +18,7 @@ use crate::{
 /// automatically rolled back.
 pub struct DatabaseTransactionFromPool<'a> {
     pub(super) transaction: Transaction<'a>,
+    pub(super) statistics_collector: Option<Arc<StatementStatisticsCollector>>,
 }
 
 impl<'a> DatabaseTransactionFromPool<'a> {
@@ -32,4 +35,12 @@ async fn get_raw_executor<'a, 'r>(
     Ok(&database_transaction.transaction)
 }
 
-impl_sql_statement_executor! {DatabaseTransactionFromPool<'a>, get_raw_executor, 'a}
+fn get_statistics_collector<'a, 'r>(
+    database_transaction: &'r DatabaseTransactionFromPool<'a>,
+) -> Option<&'r StatementStatisticsCollector> {
+    database_transaction.statistics_collector.as_deref()
+}
+
+impl_sql_statement_executor! {
+    DatabaseTransactionFromPool<'a>, get_raw_executor, get_statistics_collector, 'a
+}
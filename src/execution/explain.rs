@@ -0,0 +1,217 @@
+use deadpool_postgres::tokio_postgres::types::{FromSql, Type};
+use serde::Deserialize;
+
+use crate::{
+    error::*,
+    sql::{FieldsConsListCons, IntoSqlType, ParameterBinder, SqlJson},
+    statements::SqlStatement,
+    util::TypedConsListNil,
+};
+
+/// The execution plan of an sql statement, as returned by an `EXPLAIN`
+/// statement.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct QueryPlan {
+    /// The root node of the plan.
+    #[serde(rename = "Plan")]
+    pub root: QueryPlanNode,
+
+    /// The time it took to plan the statement, in milliseconds.
+    ///
+    /// This is only available if the statement was explained using
+    /// `EXPLAIN ANALYZE`.
+    #[serde(rename = "Planning Time")]
+    pub planning_time: Option<f64>,
+
+    /// The time it took to execute the statement, in milliseconds.
+    ///
+    /// This is only available if the statement was explained using
+    /// `EXPLAIN ANALYZE`.
+    #[serde(rename = "Execution Time")]
+    pub execution_time: Option<f64>,
+}
+impl QueryPlan {
+    /// Parses a query plan from the output of an `EXPLAIN (FORMAT JSON)`
+    /// statement.
+    pub(crate) fn from_explain_json(json: &str) -> Result<Self> {
+        // the output is an array containing a single plan for the explained
+        // statement.
+        let mut plans: Vec<QueryPlan> =
+            serde_json::from_str(json).map_err(Error::FailedToParseQueryPlan)?;
+        plans.pop().ok_or(Error::NoRecords)
+    }
+
+    /// Returns an iterator over all nodes of this plan, in depth first order,
+    /// starting from the root node.
+    pub fn nodes(&self) -> impl Iterator<Item = &QueryPlanNode> {
+        self.root.nodes()
+    }
+
+    /// Returns whether any node of this plan scans an index.
+    pub fn uses_index(&self) -> bool {
+        self.nodes().any(QueryPlanNode::is_index_scan)
+    }
+
+    /// Returns whether any node of this plan scans the index with the given
+    /// name.
+    pub fn uses_index_named(&self, index_name: &str) -> bool {
+        self.nodes()
+            .any(|node| node.index_name.as_deref() == Some(index_name))
+    }
+
+    /// Returns whether any node of this plan performs a sequential scan on the
+    /// table with the given name.
+    pub fn uses_sequential_scan_on(&self, table_name: &str) -> bool {
+        self.nodes().any(|node| {
+            node.node_type == "Seq Scan" && node.relation_name.as_deref() == Some(table_name)
+        })
+    }
+}
+
+/// A single node in the execution plan of an sql statement.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct QueryPlanNode {
+    /// The type of this node, for example `Seq Scan` or `Index Scan`.
+    #[serde(rename = "Node Type")]
+    pub node_type: String,
+
+    /// The name of the table scanned by this node, if any.
+    #[serde(rename = "Relation Name")]
+    pub relation_name: Option<String>,
+
+    /// The alias of the table scanned by this node, if any.
+    #[serde(rename = "Alias")]
+    pub alias: Option<String>,
+
+    /// The name of the index scanned by this node, if any.
+    #[serde(rename = "Index Name")]
+    pub index_name: Option<String>,
+
+    /// The type of the join performed by this node, if any.
+    #[serde(rename = "Join Type")]
+    pub join_type: Option<String>,
+
+    /// The estimated cost of returning the first row from this node.
+    #[serde(rename = "Startup Cost")]
+    pub startup_cost: f64,
+
+    /// The estimated cost of returning all rows from this node.
+    #[serde(rename = "Total Cost")]
+    pub total_cost: f64,
+
+    /// The estimated amount of rows returned from this node.
+    #[serde(rename = "Plan Rows")]
+    pub plan_rows: f64,
+
+    /// The estimated average width of the rows returned from this node, in
+    /// bytes.
+    #[serde(rename = "Plan Width")]
+    pub plan_width: u64,
+
+    /// The actual time it took to return the first row from this node, in
+    /// milliseconds.
+    ///
+    /// This is only available if the statement was explained using
+    /// `EXPLAIN ANALYZE`.
+    #[serde(rename = "Actual Startup Time")]
+    pub actual_startup_time: Option<f64>,
+
+    /// The actual time it took to return all rows from this node, in
+    /// milliseconds.
+    ///
+    /// This is only available if the statement was explained using
+    /// `EXPLAIN ANALYZE`.
+    #[serde(rename = "Actual Total Time")]
+    pub actual_total_time: Option<f64>,
+
+    /// The actual average amount of rows returned from each execution of this
+    /// node.
+    ///
+    /// This is only available if the statement was explained using
+    /// `EXPLAIN ANALYZE`.
+    #[serde(rename = "Actual Rows")]
+    pub actual_rows: Option<f64>,
+
+    /// The amount of times this node was executed.
+    ///
+    /// This is only available if the statement was explained using
+    /// `EXPLAIN ANALYZE`.
+    #[serde(rename = "Actual Loops")]
+    pub actual_loops: Option<f64>,
+
+    /// The child nodes of this node.
+    #[serde(rename = "Plans", default)]
+    pub children: Vec<QueryPlanNode>,
+}
+impl QueryPlanNode {
+    /// Returns an iterator over this node and all of its descendants, in depth
+    /// first order.
+    pub fn nodes(&self) -> impl Iterator<Item = &QueryPlanNode> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let node = stack.pop()?;
+            stack.extend(node.children.iter().rev());
+            Some(node)
+        })
+    }
+
+    /// Returns whether this node scans an index.
+    pub fn is_index_scan(&self) -> bool {
+        matches!(
+            self.node_type.as_str(),
+            "Index Scan" | "Index Only Scan" | "Bitmap Index Scan"
+        )
+    }
+}
+
+/// The raw text of a `json` value returned from the database.
+pub(crate) struct RawJson(pub String);
+impl<'a> FromSql<'a> for RawJson {
+    fn from_sql(
+        _ty: &Type,
+        raw: &'a [u8],
+    ) -> std::result::Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        // the binary format of `json` values is just the json text.
+        Ok(RawJson(std::str::from_utf8(raw)?.to_string()))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::JSON
+    }
+}
+impl IntoSqlType for RawJson {
+    type SqlType = SqlJson;
+}
+
+/// An `EXPLAIN (FORMAT JSON)` statement which explains some inner statement,
+/// and returns its execution plan as a single json value.
+pub(crate) struct ExplainStatement<S: SqlStatement> {
+    statement: S,
+    analyze: bool,
+}
+impl<S: SqlStatement> ExplainStatement<S> {
+    /// Creates a new statement which explains the given statement, and also
+    /// executes it if `analyze` is true.
+    pub(crate) fn new(statement: S, analyze: bool) -> Self {
+        Self { statement, analyze }
+    }
+}
+impl<S: SqlStatement> SqlStatement for ExplainStatement<S> {
+    type OutputFields = FieldsConsListCons<TypedConsListNil, RawJson, TypedConsListNil>;
+
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        if self.analyze {
+            f.push_str("EXPLAIN (ANALYZE, FORMAT JSON) ");
+        } else {
+            f.push_str("EXPLAIN (FORMAT JSON) ");
+        }
+        self.statement.write_sql_string(f, parameter_binder)
+    }
+}
@@ -0,0 +1,170 @@
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+
+use deadpool_postgres::tokio_postgres::types::ToSql;
+
+use super::StatementStatisticsCollector;
+use crate::error::*;
+
+/// The amount of rows that an instrumented statement modified or returned.
+#[cfg_attr(not(feature = "tracing"), allow(dead_code))]
+pub(crate) enum StatementRowsCount {
+    /// The amount of rows modified by a statement which modifies rows.
+    Modified(u64),
+
+    /// The amount of rows returned by a statement which loads rows.
+    Returned(usize),
+}
+
+/// Executes a single sql statement while instrumenting its execution.
+///
+/// The statement is executed by calling `query` with the raw executor returned
+/// from `raw_executor`, and with the statement's sql string and parameters.
+/// If the statement executes successfully, `count_rows` is called with its
+/// result to find the amount of rows that it modified or returned.
+pub(crate) async fn execute_instrumented<'a, E, T, Q, F>(
+    query_string: &'a str,
+    parameters: &'a [&'a (dyn ToSql + Sync)],
+    statistics_collector: Option<&'a StatementStatisticsCollector>,
+    raw_executor: impl Future<Output = Result<E>>,
+    query: Q,
+    count_rows: impl FnOnce(&T) -> StatementRowsCount,
+) -> Result<T>
+where
+    Q: FnOnce(E, &'a str, &'a [&'a (dyn ToSql + Sync)]) -> F,
+    F: Future<Output = Result<T>>,
+{
+    let instrumentation =
+        StatementInstrumentation::start(query_string, parameters.len(), statistics_collector);
+
+    let (result, duration) = instrumentation
+        .instrument(async {
+            let raw_executor = match raw_executor.await {
+                Ok(raw_executor) => raw_executor,
+                Err(err) => return (Err(err), None),
+            };
+
+            // the statement is only timed once the raw executor is acquired, so
+            // that the time spent waiting for a connection from a pool isn't
+            // reported as time spent executing the statement.
+            let start_time = Instant::now();
+            let result = query(raw_executor, query_string, parameters).await;
+            (result, Some(start_time.elapsed()))
+        })
+        .await;
+
+    instrumentation.finish(&result, duration, count_rows);
+    result
+}
+
+/// Instruments the execution of a single sql statement.
+///
+/// When the `tracing` feature is enabled, each statement is executed inside of
+/// a `tracing` span whose fields follow the OpenTelemetry semantic conventions
+/// for database client calls.
+///
+/// Additionally, if the statement is executed on an executor which has a
+/// statistics collector, the execution of the statement is recorded in it.
+struct StatementInstrumentation<'a> {
+    query_string: &'a str,
+    statistics_collector: Option<&'a StatementStatisticsCollector>,
+
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+}
+impl<'a> StatementInstrumentation<'a> {
+    /// Starts instrumenting the execution of the given sql statement.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    fn start(
+        query_string: &'a str,
+        parameters_amount: usize,
+        statistics_collector: Option<&'a StatementStatisticsCollector>,
+    ) -> Self {
+        Self {
+            query_string,
+            statistics_collector,
+
+            #[cfg(feature = "tracing")]
+            span: tracing::info_span!(
+                "gorm.statement",
+                otel.kind = "client",
+                otel.name = statement_operation(query_string),
+                otel.status_code = tracing::field::Empty,
+                otel.status_message = tracing::field::Empty,
+                db.system = "postgresql",
+                db.statement = query_string,
+                db.operation = statement_operation(query_string),
+                db.parameters_count = parameters_amount,
+                db.response.returned_rows = tracing::field::Empty,
+                db.rows_affected = tracing::field::Empty,
+                duration_ms = tracing::field::Empty,
+            ),
+        }
+    }
+
+    /// Runs the given future, which executes the instrumented statement,
+    /// inside of the statement's span.
+    async fn instrument<F: Future>(&self, future: F) -> F::Output {
+        #[cfg(feature = "tracing")]
+        {
+            tracing::Instrument::instrument(future, self.span.clone()).await
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        future.await
+    }
+
+    /// Finishes instrumenting the statement, recording the result of executing
+    /// it and the duration of its execution, given a function which counts the
+    /// amount of rows that it modified or returned.
+    ///
+    /// The duration is `None` if the statement wasn't executed because the raw
+    /// executor couldn't be acquired.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    fn finish<T>(
+        self,
+        result: &Result<T>,
+        duration: Option<Duration>,
+        count_rows: impl FnOnce(&T) -> StatementRowsCount,
+    ) {
+        if let (Some(statistics_collector), Some(duration)) = (self.statistics_collector, duration)
+        {
+            statistics_collector.record(self.query_string, duration);
+        }
+
+        #[cfg(feature = "tracing")]
+        {
+            if let Some(duration) = duration {
+                self.span
+                    .record("duration_ms", duration.as_secs_f64() * 1000.0);
+            }
+            match result {
+                Ok(value) => {
+                    match count_rows(value) {
+                        StatementRowsCount::Modified(rows) => {
+                            self.span.record("db.rows_affected", rows);
+                        }
+                        StatementRowsCount::Returned(rows) => {
+                            self.span.record("db.response.returned_rows", rows);
+                        }
+                    }
+                    self.span.record("otel.status_code", "OK");
+                }
+                Err(err) => {
+                    self.span.record("otel.status_code", "ERROR");
+                    self.span
+                        .record("otel.status_message", tracing::field::display(err));
+                }
+            }
+        }
+    }
+}
+
+/// Returns the sql operation performed by the given sql statement, which is the
+/// first keyword of the statement, for example `SELECT` or `INSERT`.
+#[cfg(feature = "tracing")]
+fn statement_operation(query_string: &str) -> &str {
+    query_string.split_whitespace().next().unwrap_or_default()
+}
@@ -17,10 +17,16 @@ use crate::{
 
 mod connection;
 mod connection_pool;
+mod explain;
+mod instrumentation;
+mod statistics;
 mod transaction;
 
 pub use connection::*;
 pub use connection_pool::*;
+pub use explain::*;
+pub(crate) use instrumentation::*;
+pub use statistics::*;
 pub use transaction::*;
 
 /// An executor which can execute sql statements.
@@ -112,6 +118,29 @@ pub trait SqlStatementExecutor: Sized + Send + Sync {
         &self,
         statement: S,
     ) -> Result<Vec<FieldType>>;
+
+    /// Explains the given sql statement using an `EXPLAIN` statement, and
+    /// returns the execution plan that the database chose for it, without
+    /// executing it.
+    async fn explain(&self, statement: impl SqlStatement + Send) -> Result<QueryPlan> {
+        let RawJson(plan_json) = self
+            .load_one_value(ExplainStatement::new(statement, false))
+            .await?;
+        QueryPlan::from_explain_json(&plan_json)
+    }
+
+    /// Explains the given sql statement using an `EXPLAIN ANALYZE` statement,
+    /// and returns the execution plan that the database chose for it, along
+    /// with the actual timings and row counts of each node of the plan.
+    ///
+    /// Please note that this executes the statement, so any modifications
+    /// performed by it will be applied.
+    async fn explain_analyze(&self, statement: impl SqlStatement + Send) -> Result<QueryPlan> {
+        let RawJson(plan_json) = self
+            .load_one_value(ExplainStatement::new(statement, true))
+            .await?;
+        QueryPlan::from_explain_json(&plan_json)
+    }
 }
 
 /// The result of executing an sql statement.
@@ -121,9 +150,13 @@ pub struct ExecuteResult {
 }
 
 /// Implements the [`SqlStatementExecutor`] trait for some type, given a
-/// function which returns its raw executor, and its generics.
+/// function which returns its raw executor, a function which returns its
+/// statistics collector if it has one, and its generics.
 macro_rules! impl_sql_statement_executor {
-    {$impl_for: ty, $get_raw_executor: expr $(,$($generic:tt),+)?} => {
+    {
+        $impl_for: ty, $get_raw_executor: expr, $get_statistics_collector: expr
+        $(,$($generic:tt),+)?
+    } => {
         #[async_trait::async_trait]
         impl $(< $($generic),+ >)? crate::execution::SqlStatementExecutor for $impl_for {
             async fn execute(
@@ -131,11 +164,18 @@ macro_rules! impl_sql_statement_executor {
                 statement: impl crate::statements::SqlStatement + Send,
             ) -> Result<ExecuteResult> {
                 let (query_string, parameter_binder) = statement.build();
-                let rows_modified = $get_raw_executor(self).await?
-                    .execute(&query_string, parameter_binder.parameters())
-                    .await?;
-
-                Ok(ExecuteResult { rows_modified })
+                crate::execution::execute_instrumented(
+                    &query_string,
+                    parameter_binder.parameters(),
+                    $get_statistics_collector(self),
+                    $get_raw_executor(self),
+                    |raw_executor, query_string, parameters| async move {
+                        let rows_modified = raw_executor.execute(query_string, parameters).await?;
+
+                        Ok(ExecuteResult { rows_modified })
+                    },
+                    |result| crate::execution::StatementRowsCount::Modified(result.rows_modified),
+                ).await
             }
 
             async fn load_one<O: FromQueryResult, S: SqlStatement + Send>(
@@ -148,17 +188,26 @@ macro_rules! impl_sql_statement_executor {
                 use futures::{pin_mut, TryStreamExt};
 
                 let (query_string, parameter_binder) = statement.build();
-                let row_stream = $get_raw_executor(self).await?
-                    .query_raw(&query_string, parameter_binder.parameters().iter().copied())
-                    .await?;
-
-                pin_mut!(row_stream);
-
-                let maybe_row = row_stream.try_next().await?;
-                match maybe_row {
-                    Some(row) => Ok(O::from_row(row)?),
-                    None => Err(Error::NoRecords),
-                }
+                crate::execution::execute_instrumented(
+                    &query_string,
+                    parameter_binder.parameters(),
+                    $get_statistics_collector(self),
+                    $get_raw_executor(self),
+                    |raw_executor, query_string, parameters| async move {
+                        let row_stream = raw_executor
+                            .query_raw(query_string, parameters.iter().copied())
+                            .await?;
+
+                        pin_mut!(row_stream);
+
+                        let maybe_row = row_stream.try_next().await?;
+                        match maybe_row {
+                            Some(row) => Ok(O::from_row(row)?),
+                            None => Err(Error::NoRecords),
+                        }
+                    },
+                    |_| crate::execution::StatementRowsCount::Returned(1),
+                ).await
             }
 
             async fn load_one_value<
@@ -178,17 +227,26 @@ macro_rules! impl_sql_statement_executor {
                 use futures::{pin_mut, TryStreamExt};
 
                 let (query_string, parameter_binder) = statement.build();
-                let row_stream = $get_raw_executor(self).await?
-                    .query_raw(&query_string, parameter_binder.parameters().iter().copied())
-                    .await?;
-
-                pin_mut!(row_stream);
-
-                let maybe_row = row_stream.try_next().await?;
-                match maybe_row {
-                    Some(row) => Ok(row.try_get(0)?),
-                    None => Err(Error::NoRecords),
-                }
+                crate::execution::execute_instrumented(
+                    &query_string,
+                    parameter_binder.parameters(),
+                    $get_statistics_collector(self),
+                    $get_raw_executor(self),
+                    |raw_executor, query_string, parameters| async move {
+                        let row_stream = raw_executor
+                            .query_raw(query_string, parameters.iter().copied())
+                            .await?;
+
+                        pin_mut!(row_stream);
+
+                        let maybe_row = row_stream.try_next().await?;
+                        match maybe_row {
+                            Some(row) => Ok(row.try_get(0)?),
+                            None => Err(Error::NoRecords),
+                        }
+                    },
+                    |_| crate::execution::StatementRowsCount::Returned(1),
+                ).await
             }
 
             async fn load_optional<O: FromQueryResult, S: SqlStatement + Send>(
@@ -201,17 +259,29 @@ macro_rules! impl_sql_statement_executor {
                 use futures::{pin_mut, TryStreamExt};
 
                 let (query_string, parameter_binder) = statement.build();
-                let row_stream = $get_raw_executor(self).await?
-                    .query_raw(&query_string, parameter_binder.parameters().iter().copied())
-                    .await?;
-
-                pin_mut!(row_stream);
-
-                let maybe_row = row_stream.try_next().await?;
-                match maybe_row {
-                    Some(row) => Ok(Some(O::from_row(row)?)),
-                    None => Ok(None),
-                }
+                crate::execution::execute_instrumented(
+                    &query_string,
+                    parameter_binder.parameters(),
+                    $get_statistics_collector(self),
+                    $get_raw_executor(self),
+                    |raw_executor, query_string, parameters| async move {
+                        let row_stream = raw_executor
+                            .query_raw(query_string, parameters.iter().copied())
+                            .await?;
+
+                        pin_mut!(row_stream);
+
+                        let maybe_row = row_stream.try_next().await?;
+                        match maybe_row {
+                            Some(row) => Ok(Some(O::from_row(row)?)),
+                            None => Ok(None),
+                        }
+                    },
+                    |record| {
+                        let rows = usize::from(record.is_some());
+                        crate::execution::StatementRowsCount::Returned(rows)
+                    },
+                ).await
             }
 
             async fn load_optional_value<
@@ -231,17 +301,29 @@ macro_rules! impl_sql_statement_executor {
                 use futures::{pin_mut, TryStreamExt};
 
                 let (query_string, parameter_binder) = statement.build();
-                let row_stream = $get_raw_executor(self).await?
-                    .query_raw(&query_string, parameter_binder.parameters().iter().copied())
-                    .await?;
-
-                pin_mut!(row_stream);
-
-                let maybe_row = row_stream.try_next().await?;
-                match maybe_row {
-                    Some(row) => Ok(Some(row.try_get(0)?)),
-                    None => Ok(None),
-                }
+                crate::execution::execute_instrumented(
+                    &query_string,
+                    parameter_binder.parameters(),
+                    $get_statistics_collector(self),
+                    $get_raw_executor(self),
+                    |raw_executor, query_string, parameters| async move {
+                        let row_stream = raw_executor
+                            .query_raw(query_string, parameters.iter().copied())
+                            .await?;
+
+                        pin_mut!(row_stream);
+
+                        let maybe_row = row_stream.try_next().await?;
+                        match maybe_row {
+                            Some(row) => Ok(Some(row.try_get(0)?)),
+                            None => Ok(None),
+                        }
+                    },
+                    |record| {
+                        let rows = usize::from(record.is_some());
+                        crate::execution::StatementRowsCount::Returned(rows)
+                    },
+                ).await
             }
 
             async fn load_all<O: FromQueryResult + Send, S: SqlStatement + Send>(
@@ -254,17 +336,26 @@ macro_rules! impl_sql_statement_executor {
                 use futures::{pin_mut, TryStreamExt};
 
                 let (query_string, parameter_binder) = statement.build();
-                let row_stream = $get_raw_executor(self).await?
-                    .query_raw(&query_string, parameter_binder.parameters().iter().copied())
-                    .await?;
-
-                pin_mut!(row_stream);
-
-                let mut records = Vec::new();
-                while let Some(row) = row_stream.try_next().await? {
-                    records.push(O::from_row(row)?)
-                }
-                Ok(records)
+                crate::execution::execute_instrumented(
+                    &query_string,
+                    parameter_binder.parameters(),
+                    $get_statistics_collector(self),
+                    $get_raw_executor(self),
+                    |raw_executor, query_string, parameters| async move {
+                        let row_stream = raw_executor
+                            .query_raw(query_string, parameters.iter().copied())
+                            .await?;
+
+                        pin_mut!(row_stream);
+
+                        let mut records = Vec::new();
+                        while let Some(row) = row_stream.try_next().await? {
+                            records.push(O::from_row(row)?)
+                        }
+                        Ok(records)
+                    },
+                    |records| crate::execution::StatementRowsCount::Returned(records.len()),
+                ).await
             }
 
             async fn load_all_values<
@@ -284,17 +375,75 @@ macro_rules! impl_sql_statement_executor {
                 use futures::{pin_mut, TryStreamExt};
 
                 let (query_string, parameter_binder) = statement.build();
-                let row_stream = $get_raw_executor(self).await?
-                    .query_raw(&query_string, parameter_binder.parameters().iter().copied())
-                    .await?;
+                crate::execution::execute_instrumented(
+                    &query_string,
+                    parameter_binder.parameters(),
+                    $get_statistics_collector(self),
+                    $get_raw_executor(self),
+                    |raw_executor, query_string, parameters| async move {
+                        let row_stream = raw_executor
+                            .query_raw(query_string, parameters.iter().copied())
+                            .await?;
+
+                        pin_mut!(row_stream);
+
+                        let mut records = Vec::new();
+                        while let Some(row) = row_stream.try_next().await? {
+                            records.push(row.try_get(0)?)
+                        }
+                        Ok(records)
+                    },
+                    |records| crate::execution::StatementRowsCount::Returned(records.len()),
+                ).await
+            }
 
-                pin_mut!(row_stream);
+            async fn explain(
+                &self,
+                statement: impl crate::statements::SqlStatement + Send,
+            ) -> Result<crate::execution::QueryPlan> {
+                let explain_statement = crate::execution::ExplainStatement::new(statement, false);
+                let (explain_query_string, parameter_binder) = explain_statement.build();
+
+                // explained statements aren't recorded in the statistics, since
+                // explaining a statement doesn't execute it.
+                crate::execution::execute_instrumented(
+                    &explain_query_string,
+                    parameter_binder.parameters(),
+                    None,
+                    $get_raw_executor(self),
+                    |raw_executor, query_string, parameters| async move {
+                        let row = raw_executor.query_one(query_string, parameters).await?;
+
+                        let crate::execution::RawJson(plan_json) = row.try_get(0)?;
+                        crate::execution::QueryPlan::from_explain_json(&plan_json)
+                    },
+                    |_| crate::execution::StatementRowsCount::Returned(1),
+                ).await
+            }
 
-                let mut records = Vec::new();
-                while let Some(row) = row_stream.try_next().await? {
-                    records.push(row.try_get(0)?)
-                }
-                Ok(records)
+            async fn explain_analyze(
+                &self,
+                statement: impl crate::statements::SqlStatement + Send,
+            ) -> Result<crate::execution::QueryPlan> {
+                let explain_statement = crate::execution::ExplainStatement::new(statement, true);
+                let (explain_query_string, parameter_binder) = explain_statement.build();
+
+                // explained statements aren't recorded in the statistics, so that
+                // analyzing a statement doesn't skew the statistics collected for
+                // its regular executions.
+                crate::execution::execute_instrumented(
+                    &explain_query_string,
+                    parameter_binder.parameters(),
+                    None,
+                    $get_raw_executor(self),
+                    |raw_executor, query_string, parameters| async move {
+                        let row = raw_executor.query_one(query_string, parameters).await?;
+
+                        let crate::execution::RawJson(plan_json) = row.try_get(0)?;
+                        crate::execution::QueryPlan::from_explain_json(&plan_json)
+                    },
+                    |_| crate::execution::StatementRowsCount::Returned(1),
+                ).await
             }
         }
     };
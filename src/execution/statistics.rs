@@ -0,0 +1,175 @@
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+/// A callback which is called for each statement whose execution took longer
+/// than the slow statement threshold, with the statement's sql string and the
+/// duration of its execution.
+type SlowStatementCallback = Box<dyn Fn(&str, Duration) + Send + Sync>;
+
+/// A collector of statistics about the statements executed on a database
+/// connection pool.
+///
+/// The statistics are collected per distinct sql string, as returned from
+/// [`SqlStatement::build`], so statements which only differ in the values of
+/// their bound parameters share the same statistics. Statements which are
+/// explained using [`SqlStatementExecutor::explain`] or
+/// [`SqlStatementExecutor::explain_analyze`] aren't recorded.
+///
+/// The recorded duration of a statement only includes the time spent executing
+/// it, and not the time spent waiting for a connection from the pool.
+///
+/// To collect statistics, attach a collector to a connection pool using the
+/// [`DatabaseConnectionPool::with_statistics`] function.
+///
+/// [`SqlStatement::build`]: crate::statements::SqlStatement::build
+/// [`SqlStatementExecutor::explain`]: super::SqlStatementExecutor::explain
+/// [`SqlStatementExecutor::explain_analyze`]: super::SqlStatementExecutor::explain_analyze
+/// [`DatabaseConnectionPool::with_statistics`]: super::DatabaseConnectionPool::with_statistics
+pub struct StatementStatisticsCollector {
+    statistics: Mutex<HashMap<String, StatementStatistics>>,
+    slow_statement_threshold: Option<(Duration, SlowStatementCallback)>,
+}
+
+impl StatementStatisticsCollector {
+    /// Creates a new statistics collector which has no collected statistics.
+    pub fn new() -> Self {
+        Self {
+            statistics: Mutex::new(HashMap::new()),
+            slow_statement_threshold: None,
+        }
+    }
+
+    /// Reports each statement whose execution takes longer than the given
+    /// threshold by calling the given callback with the statement's sql string
+    /// and the duration of its execution.
+    pub fn with_slow_statement_threshold(
+        mut self,
+        threshold: Duration,
+        callback: impl Fn(&str, Duration) + Send + Sync + 'static,
+    ) -> Self {
+        self.slow_statement_threshold = Some((threshold, Box::new(callback)));
+        self
+    }
+
+    /// Records a single execution of the statement with the given sql string.
+    pub(crate) fn record(&self, query_string: &str, duration: Duration) {
+        {
+            let mut statistics = self.statistics.lock().unwrap();
+            match statistics.get_mut(query_string) {
+                Some(statement_statistics) => statement_statistics.record(duration),
+                None => {
+                    let mut statement_statistics = StatementStatistics::default();
+                    statement_statistics.record(duration);
+                    statistics.insert(query_string.to_string(), statement_statistics);
+                }
+            }
+        }
+
+        // the callback is called after releasing the lock so that it can access
+        // the collected statistics.
+        if let Some((threshold, callback)) = &self.slow_statement_threshold {
+            if duration > *threshold {
+                callback(query_string, duration)
+            }
+        }
+    }
+
+    /// Returns the statistics collected for the statement with the given sql
+    /// string, if it was executed.
+    pub fn statement_statistics(&self, query_string: &str) -> Option<StatementStatistics> {
+        self.statistics.lock().unwrap().get(query_string).cloned()
+    }
+
+    /// Returns a report of the statistics collected for each statement, sorted
+    /// from the statement with the highest total execution time to the
+    /// statement with the lowest.
+    pub fn report(&self) -> StatementStatisticsReport {
+        let mut entries: Vec<_> = self
+            .statistics
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(query_string, statistics)| (query_string.clone(), statistics.clone()))
+            .collect();
+
+        entries.sort_by(|(a_query_string, a), (b_query_string, b)| {
+            b.total_duration
+                .cmp(&a.total_duration)
+                .then_with(|| a_query_string.cmp(b_query_string))
+        });
+
+        StatementStatisticsReport { entries }
+    }
+
+    /// Clears all statistics collected so far.
+    pub fn reset(&self) {
+        self.statistics.lock().unwrap().clear()
+    }
+}
+
+impl Default for StatementStatisticsCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The statistics collected for a single distinct sql statement.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatementStatistics {
+    /// The amount of times the statement was executed.
+    pub count: u64,
+
+    /// The total time spent executing the statement.
+    pub total_duration: Duration,
+
+    /// The longest time that a single execution of the statement took.
+    pub max_duration: Duration,
+}
+
+impl StatementStatistics {
+    /// Returns the average time that a single execution of the statement took.
+    pub fn average_duration(&self) -> Duration {
+        if self.count == 0 {
+            return Duration::ZERO;
+        }
+        self.total_duration.div_f64(self.count as f64)
+    }
+
+    fn record(&mut self, duration: Duration) {
+        self.count += 1;
+        self.total_duration += duration;
+        self.max_duration = self.max_duration.max(duration);
+    }
+}
+
+/// A report of the statistics collected for each statement by a
+/// [`StatementStatisticsCollector`].
+#[derive(Debug, Clone)]
+pub struct StatementStatisticsReport {
+    entries: Vec<(String, StatementStatistics)>,
+}
+
+impl StatementStatisticsReport {
+    /// Returns the sql string and the statistics of each statement in the
+    /// report, sorted from the statement with the highest total execution time
+    /// to the statement with the lowest.
+    pub fn entries(&self) -> &[(String, StatementStatistics)] {
+        &self.entries
+    }
+}
+
+impl std::fmt::Display for StatementStatisticsReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (query_string, statistics) in &self.entries {
+            writeln!(
+                f,
+                "count: {}, total: {:?}, max: {:?}, average: {:?}, sql: {}",
+                statistics.count,
+                statistics.total_duration,
+                statistics.max_duration,
+                statistics.average_duration(),
+                query_string
+            )?;
+        }
+        Ok(())
+    }
+}
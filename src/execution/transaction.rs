@@ -2,7 +2,7 @@ use deadpool_postgres::tokio_postgres::Transaction;
 
 use crate::{
     error::*,
-    execution::{impl_sql_statement_executor, ExecuteResult},
+    execution::{impl_sql_statement_executor, ExecuteResult, StatementStatisticsCollector},
     sql::FromQueryResult,
     statements::SqlStatement,
 };
@@ -32,4 +32,12 @@ async fn get_raw_executor<'a, 'r>(
     Ok(&database_transaction.transaction)
 }
 
-impl_sql_statement_executor! {DatabaseTransaction<'a>, get_raw_executor, 'a}
+fn get_statistics_collector<'a, 'r>(
+    _database_transaction: &'r DatabaseTransaction<'a>,
+) -> Option<&'r StatementStatisticsCollector> {
+    None
+}
+
+impl_sql_statement_executor! {
+    DatabaseTransaction<'a>, get_raw_executor, get_statistics_collector, 'a
+}
@@ -99,9 +99,35 @@
 //!
 //! For an example of this, check out the `migration_cli` example in the
 //! examples directory.
+//!
+//! # Tracing
+//!
+//! If you want to instrument the execution of your sql statements, you can use
+//! the `tracing` feature flag. This feature flag will execute each statement
+//! inside of a [`tracing`](https://docs.rs/tracing) span containing the sql
+//! string, the amount of bound parameters, the amount of rows returned or
+//! modified, the duration of the execution and the error if any. The fields of
+//! the span follow the OpenTelemetry semantic conventions for database calls.
+//!
+//! # Date and time
+//!
+//! If you want to use date and time columns, you can use either the `chrono`
+//! feature flag or the `time` feature flag, which map the date and time types
+//! of the [`chrono`](https://docs.rs/chrono) crate or the
+//! [`time`](https://docs.rs/time) crate to the matching sql types. Intervals are
+//! represented by the [`sql::Interval`] type, which doesn't require any feature
+//! flag.
+//!
+//! # Uuid
+//!
+//! If you want to use uuid columns, you can use the `uuid` feature flag, which
+//! maps the [`uuid::Uuid`](https://docs.rs/uuid) type to the sql `uuid` type.
+//! The `id` field of a table can also be a uuid, in which case it is generated
+//! by the database using `gen_random_uuid()` when inserting a row without an id.
 
 #![feature(auto_traits)]
 #![feature(negative_impls)]
+#![feature(with_negative_coherence)]
 
 mod error;
 pub mod execution;
@@ -115,8 +141,8 @@ pub use deadpool_postgres::tokio_postgres;
 pub use error::*;
 pub use futures;
 pub use gorm_macros::{
-    migration, returning, select_values, selected_value_to_order_by, update_set, FromQueryResult,
-    SqlEnum, Table,
+    migration, returning, select_values, selected_value_to_order_by, sql, sql_statement,
+    update_set, FromQueryResult, SqlEnum, Table,
 };
 pub use rust_decimal::Decimal;
 pub use sql::{FromQueryResult, Table};
@@ -1,10 +1,17 @@
-use deadpool_postgres::tokio_postgres::types::ToSql;
+use bytes::BytesMut;
+use deadpool_postgres::tokio_postgres::types::{FromSql, IsNull, Kind, ToSql, Type};
+use rust_decimal::Decimal;
+
+use super::Interval;
+#[cfg(any(feature = "chrono", feature = "time"))]
+use super::{SqlDate, SqlTime, SqlTimestamp, SqlTimestampTz, SqlType};
 
 /// A parameters binder which formats bound parameters as $1 .. $N and saves the
 /// actual values in a list.
 pub struct ParameterBinder<'a> {
     parameters: Vec<&'a (dyn ToSql + Sync)>,
     cur_n: usize,
+    inline_parameters: bool,
 }
 impl<'a> ParameterBinder<'a> {
     /// Creates a new parameter binder with an empty list of values, and which
@@ -15,6 +22,19 @@ impl<'a> ParameterBinder<'a> {
         Self {
             cur_n: 1,
             parameters: Default::default(),
+            inline_parameters: false,
+        }
+    }
+
+    /// Creates a new parameter binder which formats bound parameters as sql
+    /// literals containing their values instead of as $1 .. $N.
+    ///
+    /// The resulting sql is meant for debugging purposes only, and should not
+    /// be executed on the database.
+    pub fn new_inlining() -> Self {
+        Self {
+            inline_parameters: true,
+            ..Self::new()
         }
     }
 
@@ -27,7 +47,11 @@ impl<'a> ParameterBinder<'a> {
     ) -> DisplayableBoundParameterDollarN {
         self.parameters.push(parameter);
 
-        let result = DisplayableBoundParameterDollarN { n: self.cur_n };
+        let result = if self.inline_parameters {
+            DisplayableBoundParameterDollarN::Inlined(format_parameter_as_sql_literal(parameter))
+        } else {
+            DisplayableBoundParameterDollarN::DollarN(self.cur_n)
+        };
 
         self.cur_n += 1;
 
@@ -42,12 +66,192 @@ impl<'a> ParameterBinder<'a> {
 }
 
 /// A struct which implements the [`std::fmt::Display`] trait and allows
-/// formatting a bound parameter's sql identifier ($1..$N).
-pub struct DisplayableBoundParameterDollarN {
-    n: usize,
+/// formatting a bound parameter's sql identifier ($1..$N), or its value as an
+/// sql literal if it was bound using an inlining parameter binder.
+pub enum DisplayableBoundParameterDollarN {
+    /// The parameter is formatted as its sql identifier, `$N`.
+    DollarN(usize),
+
+    /// The parameter is formatted as an sql literal containing its value.
+    Inlined(String),
 }
 impl std::fmt::Display for DisplayableBoundParameterDollarN {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "${}", self.n)
+        match self {
+            DisplayableBoundParameterDollarN::DollarN(n) => write!(f, "${}", n),
+            DisplayableBoundParameterDollarN::Inlined(literal) => f.write_str(literal),
+        }
+    }
+}
+
+/// Formats the given parameter as an sql literal containing its value.
+///
+/// The value of the parameter is extracted by serializing it as each of the
+/// supported sql types until one of them is accepted, and then deserializing it
+/// as the matching rust type. Parameters whose type is not supported are
+/// formatted as a quoted string containing their debug representation.
+fn format_parameter_as_sql_literal(parameter: &(dyn ToSql + Sync)) -> String {
+    const SUPPORTED_TYPES: &[Type] = &[
+        Type::BOOL,
+        Type::CHAR,
+        Type::INT2,
+        Type::INT4,
+        Type::INT8,
+        Type::FLOAT4,
+        Type::FLOAT8,
+        Type::NUMERIC,
+        Type::TEXT,
+        Type::BYTEA,
+        Type::TIMESTAMP,
+        Type::TIMESTAMPTZ,
+        Type::DATE,
+        Type::TIME,
+        Type::INTERVAL,
+        Type::UUID,
+        Type::JSON,
+        Type::JSONB,
+        Type::BOOL_ARRAY,
+        Type::INT2_ARRAY,
+        Type::INT4_ARRAY,
+        Type::INT8_ARRAY,
+        Type::FLOAT4_ARRAY,
+        Type::FLOAT8_ARRAY,
+        Type::NUMERIC_ARRAY,
+        Type::TEXT_ARRAY,
+        Type::BYTEA_ARRAY,
+        Type::TIMESTAMP_ARRAY,
+        Type::TIMESTAMPTZ_ARRAY,
+        Type::DATE_ARRAY,
+        Type::TIME_ARRAY,
+        Type::INTERVAL_ARRAY,
+        Type::UUID_ARRAY,
+        Type::JSON_ARRAY,
+        Type::JSONB_ARRAY,
+    ];
+
+    for ty in SUPPORTED_TYPES {
+        let mut buf = BytesMut::new();
+        match parameter.to_sql_checked(ty, &mut buf) {
+            Ok(IsNull::Yes) => return "NULL".to_string(),
+            Ok(IsNull::No) => {
+                if let Some(literal) = format_serialized_value_as_sql_literal(ty, &buf) {
+                    return literal;
+                }
+            }
+            Err(_) => {}
+        }
+    }
+
+    quote_sql_string(&format!("{:?}", parameter))
+}
+
+/// Deserializes a value of the given sql type and formats it as an sql literal.
+fn format_serialized_value_as_sql_literal(ty: &Type, raw: &[u8]) -> Option<String> {
+    let literal = match *ty {
+        Type::BOOL => match bool::from_sql(ty, raw).ok()? {
+            true => "TRUE".to_string(),
+            false => "FALSE".to_string(),
+        },
+        Type::CHAR => i8::from_sql(ty, raw).ok()?.to_string(),
+        Type::INT2 => i16::from_sql(ty, raw).ok()?.to_string(),
+        Type::INT4 => i32::from_sql(ty, raw).ok()?.to_string(),
+        Type::INT8 => i64::from_sql(ty, raw).ok()?.to_string(),
+        Type::FLOAT4 => format_float_as_sql_literal(f32::from_sql(ty, raw).ok()?.into()),
+        Type::FLOAT8 => format_float_as_sql_literal(f64::from_sql(ty, raw).ok()?),
+        Type::NUMERIC => Decimal::from_sql(ty, raw).ok()?.to_string(),
+        Type::TEXT => quote_sql_string(&String::from_sql(ty, raw).ok()?),
+        Type::BYTEA => {
+            let bytes = <Vec<u8>>::from_sql(ty, raw).ok()?;
+            let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+            format!("'\\x{}'", hex)
+        }
+        #[cfg(any(feature = "chrono", feature = "time"))]
+        Type::TIMESTAMP => quote_sql_string(
+            &<SqlTimestamp as SqlType>::RustType::from_sql(ty, raw)
+                .ok()?
+                .to_string(),
+        ),
+        #[cfg(any(feature = "chrono", feature = "time"))]
+        Type::TIMESTAMPTZ => quote_sql_string(
+            &<SqlTimestampTz as SqlType>::RustType::from_sql(ty, raw)
+                .ok()?
+                .to_string(),
+        ),
+        #[cfg(any(feature = "chrono", feature = "time"))]
+        Type::DATE => quote_sql_string(
+            &<SqlDate as SqlType>::RustType::from_sql(ty, raw)
+                .ok()?
+                .to_string(),
+        ),
+        #[cfg(any(feature = "chrono", feature = "time"))]
+        Type::TIME => quote_sql_string(
+            &<SqlTime as SqlType>::RustType::from_sql(ty, raw)
+                .ok()?
+                .to_string(),
+        ),
+        #[cfg(feature = "uuid")]
+        Type::UUID => quote_sql_string(&uuid::Uuid::from_sql(ty, raw).ok()?.to_string()),
+        Type::JSON | Type::JSONB => {
+            quote_sql_string(&serde_json::Value::from_sql(ty, raw).ok()?.to_string())
+        }
+        Type::INTERVAL => {
+            let interval = Interval::from_sql(ty, raw).ok()?;
+            format!(
+                "'{} mons {} days {} microseconds'",
+                interval.months, interval.days, interval.microseconds
+            )
+        }
+        _ => match ty.kind() {
+            Kind::Array(element_ty) => {
+                let elements = <Vec<Option<RawSqlValue>>>::from_sql(ty, raw).ok()?;
+                let mut literals = Vec::with_capacity(elements.len());
+                for element in elements {
+                    literals.push(match element {
+                        Some(RawSqlValue(element_raw)) => {
+                            format_serialized_value_as_sql_literal(element_ty, element_raw)?
+                        }
+                        None => "NULL".to_string(),
+                    });
+                }
+                format!("ARRAY[{}]::{}[]", literals.join(","), element_ty.name())
+            }
+            _ => return None,
+        },
+    };
+    Some(literal)
+}
+
+/// The raw serialized bytes of a single sql value, used for deserializing the
+/// elements of an array before deserializing each element as its own type.
+struct RawSqlValue<'a>(&'a [u8]);
+impl<'a> FromSql<'a> for RawSqlValue<'a> {
+    fn from_sql(
+        _ty: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        Ok(Self(raw))
     }
+
+    fn accepts(_ty: &Type) -> bool {
+        true
+    }
+}
+
+fn format_float_as_sql_literal(value: f64) -> String {
+    if value.is_nan() {
+        "'NaN'".to_string()
+    } else if value.is_infinite() {
+        if value > 0.0 {
+            "'Infinity'".to_string()
+        } else {
+            "'-Infinity'".to_string()
+        }
+    } else {
+        format!("{:?}", value)
+    }
+}
+
+/// Quotes the given string as an sql string literal, escaping any quotes in it.
+fn quote_sql_string(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
 }
@@ -0,0 +1,77 @@
+use std::{
+    error::Error,
+    ops::{Deref, DerefMut},
+};
+
+use bytes::{BufMut, Bytes, BytesMut};
+use deadpool_postgres::tokio_postgres::types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+/// A wrapper around [`Bytes`] which stores it in the database as an sql
+/// `bytea` value.
+///
+/// This wrapper is required because [`Bytes`] can't be converted to and from
+/// sql values by itself. A [`Vec<u8>`] can also be used directly as a `bytea`
+/// value, without this wrapper.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Bytea(pub Bytes);
+
+impl Bytea {
+    /// Returns the wrapped bytes.
+    pub fn into_inner(self) -> Bytes {
+        self.0
+    }
+}
+
+impl Deref for Bytea {
+    type Target = Bytes;
+
+    fn deref(&self) -> &Bytes {
+        &self.0
+    }
+}
+
+impl DerefMut for Bytea {
+    fn deref_mut(&mut self) -> &mut Bytes {
+        &mut self.0
+    }
+}
+
+impl From<Bytes> for Bytea {
+    fn from(bytes: Bytes) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<Vec<u8>> for Bytea {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes.into())
+    }
+}
+
+impl<'a> FromSql<'a> for Bytea {
+    fn from_sql(_ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(Self(Bytes::copy_from_slice(raw)))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::BYTEA
+    }
+}
+
+impl ToSql for Bytea {
+    fn to_sql(
+        &self,
+        _ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        out.put_slice(&self.0);
+
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::BYTEA
+    }
+
+    to_sql_checked!();
+}
@@ -1,18 +1,37 @@
 use std::{fmt::Write, marker::PhantomData};
 
+use deadpool_postgres::tokio_postgres::types::ToSql;
+
 use super::SqlText;
-use crate::sql::{OrderableSqlType, ParameterBinder, SelectableTables, SqlBool, SqlExpression};
+use crate::{
+    sql::{
+        ArrayElementSqlType, IntoSqlType, OrderableSqlType, ParameterBinder, SelectableTables,
+        SqlBool, SqlComparable, SqlExpression, SqlType,
+    },
+    util::TypedTrue,
+};
 
 /// Defines an operator condition struct.
+///
+/// If the `@parenthesized` prefix is used, the operands of the condition are
+/// parenthesized when converted to sql, which is required for operators which
+/// don't bind looser than the operators of their operands, for example `~`,
+/// which has the same precedence as `||`.
 macro_rules! define_operator_condition{
-    {$type_name: ident, $operator: literal $(,sqltype = $sqltype: ident)? $(,
+    {@parenthesized $($rest: tt)*} => {
+        define_operator_condition! {@with_write_operand write_parenthesized_sql_string, $($rest)*}
+    };
+    {
+        @with_write_operand $write_operand: ident,
+        $type_name: ident, $operator: literal $(,sqltype = $sqltype: ident)? $(,
         where $(
-                    $bounded_type:path: $bound:tt $(+ $others:tt ),*
-                )+
+                    $bounded_type:path: $bound:tt $(+ $others:tt )*
+                ),+
     )?} => {
-        pub struct $type_name<S: SelectableTables, Lhs: SqlExpression<S $(, SqlType = $sqltype)?>, Rhs: SqlExpression<S, SqlType = Lhs::SqlType>>
-        $(
+        pub struct $type_name<S: SelectableTables, Lhs: SqlExpression<S $(, SqlType = $sqltype)?>, Rhs: SqlExpression<S>>
         where
+            Lhs::SqlType: SqlComparable<Rhs::SqlType>,
+        $(
             $(
                 $bounded_type : $bound $(+ $others)*,
             )+
@@ -23,12 +42,13 @@ macro_rules! define_operator_condition{
             phantom: PhantomData<S>,
         }
 
-        impl<S: SelectableTables, Lhs: SqlExpression<S $(, SqlType = $sqltype)?>, Rhs: SqlExpression<S, SqlType = Lhs::SqlType>>
+        impl<S: SelectableTables, Lhs: SqlExpression<S $(, SqlType = $sqltype)?>, Rhs: SqlExpression<S>>
             $type_name<S, Lhs, Rhs>
-        $(
         where
+            Lhs::SqlType: SqlComparable<Rhs::SqlType>,
+        $(
             $(
-                $bounded_type : $bound $(+ $others),*
+                $bounded_type : $bound $(+ $others)*,
             )+
         )?
         {
@@ -41,12 +61,13 @@ macro_rules! define_operator_condition{
             }
         }
 
-        impl<S: SelectableTables, Lhs: SqlExpression<S $(, SqlType = $sqltype)?>, Rhs: SqlExpression<S, SqlType = Lhs::SqlType>> SqlExpression<S>
+        impl<S: SelectableTables, Lhs: SqlExpression<S $(, SqlType = $sqltype)?>, Rhs: SqlExpression<S>> SqlExpression<S>
             for $type_name<S, Lhs, Rhs>
-        $(
         where
+            Lhs::SqlType: SqlComparable<Rhs::SqlType>,
+        $(
             $(
-                $bounded_type : $bound $(+ $others),*
+                $bounded_type : $bound $(+ $others)*,
             )+
         )?
         {
@@ -63,13 +84,16 @@ macro_rules! define_operator_condition{
             where
                 's: 'a,
             {
-                self.lhs.write_sql_string(f, parameter_binder)?;
+                self.lhs.$write_operand(f, parameter_binder)?;
                 write!(f, $operator)?;
-                self.rhs.write_sql_string(f, parameter_binder)?;
+                self.rhs.$write_operand(f, parameter_binder)?;
                 Ok(())
             }
         }
-    }
+    };
+    {$type_name: ident $($rest: tt)*} => {
+        define_operator_condition! {@with_write_operand write_sql_string, $type_name $($rest)*}
+    };
 }
 
 define_operator_condition! {SqlConditionEq, "="}
@@ -88,3 +112,187 @@ define_operator_condition_orderable! {SqlConditionGreaterEquals, ">="}
 
 define_operator_condition! {SqlConditionLike, " LIKE ", sqltype = SqlText}
 define_operator_condition! {SqlConditionNotLike, " NOT LIKE ", sqltype = SqlText}
+define_operator_condition! {@parenthesized SqlConditionILike, " ILIKE ", sqltype = SqlText}
+define_operator_condition! {@parenthesized SqlConditionNotILike, " NOT ILIKE ", sqltype = SqlText}
+define_operator_condition! {@parenthesized SqlConditionSimilarTo, " SIMILAR TO ", sqltype = SqlText}
+define_operator_condition! {@parenthesized SqlConditionNotSimilarTo, " NOT SIMILAR TO ", sqltype = SqlText}
+define_operator_condition! {@parenthesized SqlConditionMatchesRegex, " ~ ", sqltype = SqlText}
+define_operator_condition! {@parenthesized SqlConditionMatchesRegexCaseInsensitive, " ~* ", sqltype = SqlText}
+define_operator_condition! {@parenthesized SqlConditionNotMatchesRegex, " !~ ", sqltype = SqlText}
+
+define_operator_condition! {SqlConditionIsDistinctFrom, " IS DISTINCT FROM "}
+define_operator_condition! {SqlConditionIsNotDistinctFrom, " IS NOT DISTINCT FROM "}
+
+/// Defines a range condition struct, which checks if an expression is within
+/// the range between a lower and an upper bound.
+macro_rules! define_range_condition{
+    {$type_name: ident, $operator: literal} => {
+        pub struct $type_name<S: SelectableTables, E: SqlExpression<S>, L: SqlExpression<S>, H: SqlExpression<S>>
+        where
+            E::SqlType: OrderableSqlType + SqlComparable<L::SqlType> + SqlComparable<H::SqlType>,
+        {
+            expression: E,
+            low: L,
+            high: H,
+            phantom: PhantomData<S>,
+        }
+
+        impl<S: SelectableTables, E: SqlExpression<S>, L: SqlExpression<S>, H: SqlExpression<S>>
+            $type_name<S, E, L, H>
+        where
+            E::SqlType: OrderableSqlType + SqlComparable<L::SqlType> + SqlComparable<H::SqlType>,
+        {
+            pub fn new(expression: E, low: L, high: H) -> Self {
+                Self {
+                    expression,
+                    low,
+                    high,
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<S: SelectableTables, E: SqlExpression<S>, L: SqlExpression<S>, H: SqlExpression<S>>
+            SqlExpression<S> for $type_name<S, E, L, H>
+        where
+            E::SqlType: OrderableSqlType + SqlComparable<L::SqlType> + SqlComparable<H::SqlType>,
+        {
+            type SqlType = SqlBool;
+            type RustType = bool;
+
+            const IS_AGGREGATE:bool = E::IS_AGGREGATE || L::IS_AGGREGATE || H::IS_AGGREGATE;
+
+            fn write_sql_string<'s, 'a>(
+                &'s self,
+                f: &mut String,
+                parameter_binder: &mut ParameterBinder<'a>,
+            ) -> std::fmt::Result
+            where
+                's: 'a,
+            {
+                self.expression.write_sql_string(f, parameter_binder)?;
+                write!(f, $operator)?;
+                self.low.write_sql_string(f, parameter_binder)?;
+                write!(f, " AND ")?;
+                self.high.write_sql_string(f, parameter_binder)?;
+                Ok(())
+            }
+        }
+    }
+}
+
+define_range_condition! {SqlConditionBetween, " BETWEEN "}
+define_range_condition! {SqlConditionNotBetween, " NOT BETWEEN "}
+
+/// Defines a postfix operator condition struct, which checks some property of
+/// a single nullable expression.
+macro_rules! define_nullable_postfix_operator_condition{
+    {$type_name: ident, $operator: literal} => {
+        pub struct $type_name<S: SelectableTables, E: SqlExpression<S>>
+        where
+            E::SqlType: SqlType<IsNull = TypedTrue>,
+        {
+            expression: E,
+            phantom: PhantomData<S>,
+        }
+
+        impl<S: SelectableTables, E: SqlExpression<S>> $type_name<S, E>
+        where
+            E::SqlType: SqlType<IsNull = TypedTrue>,
+        {
+            pub fn new(expression: E) -> Self {
+                Self {
+                    expression,
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<S: SelectableTables, E: SqlExpression<S>> SqlExpression<S> for $type_name<S, E>
+        where
+            E::SqlType: SqlType<IsNull = TypedTrue>,
+        {
+            type SqlType = SqlBool;
+            type RustType = bool;
+
+            const IS_AGGREGATE:bool = E::IS_AGGREGATE;
+
+            fn write_sql_string<'s, 'a>(
+                &'s self,
+                f: &mut String,
+                parameter_binder: &mut ParameterBinder<'a>,
+            ) -> std::fmt::Result
+            where
+                's: 'a,
+            {
+                self.expression.write_sql_string(f, parameter_binder)?;
+                write!(f, $operator)
+            }
+        }
+    }
+}
+
+define_nullable_postfix_operator_condition! {SqlConditionIsNull, " IS NULL"}
+define_nullable_postfix_operator_condition! {SqlConditionIsNotNull, " IS NOT NULL"}
+
+/// Defines a list condition struct, which compares an expression with each
+/// value in a list of values, which is bound as a single array parameter.
+macro_rules! define_list_condition{
+    {$type_name: ident, $operator: literal} => {
+        pub struct $type_name<S: SelectableTables, E: SqlExpression<S>, V: IntoSqlType + ToSql + Sync>
+        where
+            E::SqlType: SqlComparable<V::SqlType>,
+            V::SqlType: ArrayElementSqlType,
+        {
+            expression: E,
+            values: Vec<V>,
+            phantom: PhantomData<S>,
+        }
+
+        impl<S: SelectableTables, E: SqlExpression<S>, V: IntoSqlType + ToSql + Sync> $type_name<S, E, V>
+        where
+            E::SqlType: SqlComparable<V::SqlType>,
+            V::SqlType: ArrayElementSqlType,
+        {
+            pub fn new(expression: E, values: Vec<V>) -> Self {
+                Self {
+                    expression,
+                    values,
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<S: SelectableTables, E: SqlExpression<S>, V: IntoSqlType + ToSql + Sync> SqlExpression<S>
+            for $type_name<S, E, V>
+        where
+            E::SqlType: SqlComparable<V::SqlType>,
+            V::SqlType: ArrayElementSqlType,
+        {
+            type SqlType = SqlBool;
+            type RustType = bool;
+
+            const IS_AGGREGATE:bool = E::IS_AGGREGATE;
+
+            fn write_sql_string<'s, 'a>(
+                &'s self,
+                f: &mut String,
+                parameter_binder: &mut ParameterBinder<'a>,
+            ) -> std::fmt::Result
+            where
+                's: 'a,
+            {
+                self.expression.write_sql_string(f, parameter_binder)?;
+                write!(f, $operator)?;
+                SqlExpression::<S>::write_parenthesized_sql_string(
+                    &self.values,
+                    f,
+                    parameter_binder,
+                )
+            }
+        }
+    }
+}
+
+define_list_condition! {SqlConditionInList, " = ANY"}
+define_list_condition! {SqlConditionNotInList, " <> ALL"}
@@ -1,15 +1,40 @@
-use std::fmt::Write;
+use std::fmt::{Debug, Write};
+
+use deadpool_postgres::tokio_postgres::types::ToSql;
+use rust_decimal::Decimal;
+use serde::Serialize;
 
 use super::{
-    AverageableSqlType, SqlAdd, SqlAddition, SqlAverage, SqlConditionLike, SqlConditionNotLike,
-    SqlCount, SqlDivide, SqlDivision, SqlMax, SqlMultiplication, SqlMultiply, SqlSubtract,
-    SqlSubtraction, SqlSum, SummableSqlType,
+    ArrayElementSqlType, ArraySqlType, AverageableSqlType, Bytea, ContinuousPercentileSqlType,
+    DateTimeField, DifferenceableSqlType, Interval, Json, JsonSqlType, Jsonb, MathFunctionSqlType,
+    SqlAbs, SqlAdd, SqlAddition, SqlAge, SqlArray, SqlArrayAgg, SqlArrayAppend,
+    SqlArrayContainedBy, SqlArrayContains, SqlArrayLength, SqlArrayOverlaps, SqlAverage,
+    SqlBitwise, SqlBitwiseAnd, SqlBitwiseNot, SqlBitwiseOr, SqlBitwiseXor, SqlBoolAnd, SqlBoolOr,
+    SqlBytea, SqlByteaLength, SqlByteaSubstring, SqlCast, SqlCastable, SqlCeil, SqlCoalesce,
+    SqlCoalesceFirstArgument, SqlConcat, SqlConditionLike, SqlConditionNotLike, SqlCount,
+    SqlDateTrunc, SqlDifference, SqlDivide, SqlDivision, SqlExtract, SqlF64, SqlFloor, SqlGreatest,
+    SqlI32, SqlJsonGet, SqlJsonGetIndex, SqlJsonGetIndexText, SqlJsonGetPath, SqlJsonGetText,
+    SqlJsonb, SqlJsonbContains, SqlJsonbHasKey, SqlJsonbSet, SqlLeast, SqlLeft, SqlLength,
+    SqlLower, SqlMax, SqlMd5, SqlMod, SqlModulo, SqlMultiplication, SqlMultiply, SqlNullIf,
+    SqlNumeric, SqlOption, SqlPercentileCont, SqlPercentileDisc, SqlPosition, SqlPower, SqlReplace,
+    SqlRight, SqlRound, SqlSha224, SqlSha256, SqlSha384, SqlSha512, SqlShift, SqlShiftLeft,
+    SqlShiftRight, SqlSqrt, SqlStartsWith, SqlStddev, SqlStringAgg, SqlSubstring, SqlSubtract,
+    SqlSubtraction, SqlSum, SqlTrim, SqlUnnest, SqlUpper, SqlVariance, StatisticalSqlType,
+    SummableSqlType, TemporalSqlType, TimestampSqlType,
 };
-use crate::sql::{
-    Column, IntoSqlType, OrderableSqlType, ParameterBinder, SelectableTables,
-    SelectableTablesContains, SqlBool, SqlBooleanAnd, SqlBooleanOr, SqlConditionEq,
-    SqlConditionGreaterEquals, SqlConditionGreaterThan, SqlConditionLowerEquals,
-    SqlConditionLowerThan, SqlConditionNotEq, SqlText, SqlType, Table,
+use crate::{
+    sql::{
+        Column, IntoSqlType, OrderableSqlType, ParameterBinder, SelectableTables,
+        SelectableTablesContains, SqlBool, SqlBooleanAnd, SqlBooleanOr, SqlComparable,
+        SqlConditionBetween, SqlConditionEq, SqlConditionEqualsAny, SqlConditionGreaterEquals,
+        SqlConditionGreaterThan, SqlConditionILike, SqlConditionInList, SqlConditionIsDistinctFrom,
+        SqlConditionIsNotDistinctFrom, SqlConditionIsNotNull, SqlConditionIsNull,
+        SqlConditionLowerEquals, SqlConditionLowerThan, SqlConditionMatchesRegex,
+        SqlConditionMatchesRegexCaseInsensitive, SqlConditionNotBetween, SqlConditionNotEq,
+        SqlConditionNotILike, SqlConditionNotInList, SqlConditionNotMatchesRegex,
+        SqlConditionNotSimilarTo, SqlConditionSimilarTo, SqlText, SqlType, Table,
+    },
+    util::{TypedFalse, TypedTrue, TypesEqual},
 };
 
 /// An sql expression.
@@ -51,29 +76,158 @@ pub trait SqlExpression<S: SelectableTables>: Sized {
 
     /// Returns a condition which will be true if the given expression is equal
     /// to this one.
-    // only allow equality for expression with the same value type
-    fn equals<O: SqlExpression<S, SqlType = <Self as SqlExpression<S>>::SqlType>>(
-        self,
-        other: O,
-    ) -> SqlConditionEq<S, Self, O> {
+    ///
+    /// Just like in sql, comparing a null value using this condition evaluates
+    /// to null, so for example `expr.equals(None::<i32>)` never matches any
+    /// rows. To check if an expression is null use
+    /// [`NullableSqlExpression::is_null`], and to compare it to an optional
+    /// value use [`NullableSqlExpression::is_not_distinct_from`].
+    // only allow equality for expressions with comparable value types
+    fn equals<O: SqlExpression<S>>(self, other: O) -> SqlConditionEq<S, Self, O>
+    where
+        Self::SqlType: SqlComparable<O::SqlType>,
+    {
         SqlConditionEq::new(self, other)
     }
 
     /// Returns a condition which will be true if the given expression is not
     /// equal to this one.
-    // only allow equality for expression with the same value type
-    fn not_equals<O: SqlExpression<S, SqlType = <Self as SqlExpression<S>>::SqlType>>(
-        self,
-        other: O,
-    ) -> SqlConditionNotEq<S, Self, O> {
+    ///
+    /// Just like in sql, comparing a null value using this condition evaluates
+    /// to null, so for example `expr.not_equals(None::<i32>)` never matches any
+    /// rows. To check if an expression is not null use
+    /// [`NullableSqlExpression::is_not_null`], and to compare it to an
+    /// optional value use [`NullableSqlExpression::is_distinct_from`].
+    // only allow equality for expressions with comparable value types
+    fn not_equals<O: SqlExpression<S>>(self, other: O) -> SqlConditionNotEq<S, Self, O>
+    where
+        Self::SqlType: SqlComparable<O::SqlType>,
+    {
         SqlConditionNotEq::new(self, other)
     }
 
+    /// Returns an expression which evaluates to this expression converted to
+    /// the sql type `To`.
+    ///
+    /// Only casts which are allowed by the database can be performed. If this
+    /// expression is nullable, the result is nullable as well.
+    fn cast<To: SqlType>(self) -> SqlCast<S, Self, To>
+    where
+        <Self::SqlType as SqlType>::NonNullSqlType: SqlCastable<To>,
+        (To::IsNull, TypedFalse): TypesEqual,
+    {
+        SqlCast::new(self)
+    }
+
+    /// Returns an expression which evaluates to null if this expression is
+    /// equal to the given one, or to this expression otherwise.
+    fn nullif<O: SqlExpression<S>>(self, other: O) -> SqlNullIf<S, Self, O>
+    where
+        Self::SqlType: SqlType<NonNullSqlType = <O::SqlType as SqlType>::NonNullSqlType>,
+        (
+            <<Self::SqlType as SqlType>::NonNullSqlType as SqlType>::IsNull,
+            TypedFalse,
+        ): TypesEqual,
+    {
+        SqlNullIf::new(self, other)
+    }
+
+    /// Returns a condition which will be true if this expression is equal to
+    /// any of the given values.
+    ///
+    /// The values can be given as a vector, an array or a slice, and are bound
+    /// as a single array parameter, so the sql string of the statement doesn't
+    /// depend on the amount of values.
+    fn in_list<V: IntoSqlType + ToSql + Sync>(
+        self,
+        values: impl Into<Vec<V>>,
+    ) -> SqlConditionInList<S, Self, V>
+    where
+        Self::SqlType: SqlComparable<V::SqlType>,
+        V::SqlType: ArrayElementSqlType,
+    {
+        SqlConditionInList::new(self, values.into())
+    }
+
+    /// Returns a condition which will be true if this expression is not equal
+    /// to any of the given values.
+    ///
+    /// The values can be given as a vector, an array or a slice, and are bound
+    /// as a single array parameter, so the sql string of the statement doesn't
+    /// depend on the amount of values.
+    fn not_in_list<V: IntoSqlType + ToSql + Sync>(
+        self,
+        values: impl Into<Vec<V>>,
+    ) -> SqlConditionNotInList<S, Self, V>
+    where
+        Self::SqlType: SqlComparable<V::SqlType>,
+        V::SqlType: ArrayElementSqlType,
+    {
+        SqlConditionNotInList::new(self, values.into())
+    }
+
+    /// Returns a condition which will be true if this expression is equal to
+    /// any of the elements of the given array expression.
+    ///
+    /// This will be translated to `expr = ANY(array)` when converted to sql.
+    fn equals_any<A: SqlExpression<S>>(self, array: A) -> SqlConditionEqualsAny<S, Self, A>
+    where
+        <A::SqlType as SqlType>::NonNullSqlType: ArraySqlType,
+        Self::SqlType: SqlComparable<
+            <<A::SqlType as SqlType>::NonNullSqlType as ArraySqlType>::ElementSqlType,
+        >,
+    {
+        SqlConditionEqualsAny::new(self, array)
+    }
+
     /// Returns an expression which evaluates to the amount of items returned
     /// from the query.
     fn count(self) -> SqlCount<S, Self> {
         SqlCount::new(self)
     }
+
+    /// Returns an expression which evaluates to an array of the values
+    /// returned from the query.
+    fn array_agg(self) -> SqlArrayAgg<S, Self>
+    where
+        Self::SqlType: ArrayElementSqlType,
+    {
+        SqlArrayAgg::new(self)
+    }
+
+    /// Returns an expression which evaluates to true if all of the values
+    /// returned from the query are true, or to null if all of them are null.
+    fn bool_and(self) -> SqlBoolAnd<S, Self>
+    where
+        Self::SqlType: SqlType<NonNullSqlType = SqlBool>,
+    {
+        SqlBoolAnd::new(self)
+    }
+
+    /// Returns an expression which evaluates to true if any of the values
+    /// returned from the query is true, or to null if all of them are null.
+    fn bool_or(self) -> SqlBoolOr<S, Self>
+    where
+        Self::SqlType: SqlType<NonNullSqlType = SqlBool>,
+    {
+        SqlBoolOr::new(self)
+    }
+
+    /// Returns an expression which evaluates to the continuous percentile of
+    /// the values returned from the query, interpolating between adjacent
+    /// values if needed, or to null if all of the values are null.
+    ///
+    /// The fraction should be between 0 and 1, for example a fraction of 0.5
+    /// finds the median.
+    fn percentile_cont<F: SqlExpression<S, SqlType = SqlF64>>(
+        self,
+        fraction: F,
+    ) -> SqlPercentileCont<S, F, Self>
+    where
+        Self::SqlType: ContinuousPercentileSqlType,
+    {
+        SqlPercentileCont::new(fraction, self)
+    }
 }
 
 // A column is an sql expression.
@@ -111,55 +265,194 @@ where
 {
     /// Returns a condition which will be true if this expression is lower than
     /// the given one.
-    // Only allow comparing with expression with the same value type
-    fn lower_than<O: SqlExpression<S, SqlType = <Self as SqlExpression<S>>::SqlType>>(
-        self,
-        other: O,
-    ) -> SqlConditionLowerThan<S, Self, O> {
+    // Only allow comparing with expressions with comparable value types
+    fn lower_than<O: SqlExpression<S>>(self, other: O) -> SqlConditionLowerThan<S, Self, O>
+    where
+        Self::SqlType: SqlComparable<O::SqlType>,
+    {
         SqlConditionLowerThan::new(self, other)
     }
 
     /// Returns a condition which will be true if this expression is lower or
     /// equal to the given one.
-    // Only allow comparing with expression with the same value type
-    fn lower_equals<O: SqlExpression<S, SqlType = <Self as SqlExpression<S>>::SqlType>>(
-        self,
-        other: O,
-    ) -> SqlConditionLowerEquals<S, Self, O> {
+    // Only allow comparing with expressions with comparable value types
+    fn lower_equals<O: SqlExpression<S>>(self, other: O) -> SqlConditionLowerEquals<S, Self, O>
+    where
+        Self::SqlType: SqlComparable<O::SqlType>,
+    {
         SqlConditionLowerEquals::new(self, other)
     }
 
     /// Returns a condition which will be true if this expression is greater
     /// than the given one.
-    // Only allow comparing with expression with the same value type
-    fn greater_than<O: SqlExpression<S, SqlType = <Self as SqlExpression<S>>::SqlType>>(
-        self,
-        other: O,
-    ) -> SqlConditionGreaterThan<S, Self, O> {
+    // Only allow comparing with expressions with comparable value types
+    fn greater_than<O: SqlExpression<S>>(self, other: O) -> SqlConditionGreaterThan<S, Self, O>
+    where
+        Self::SqlType: SqlComparable<O::SqlType>,
+    {
         SqlConditionGreaterThan::new(self, other)
     }
 
     /// Returns a condition which will be true if this expression is greater or
     /// equal to the given one.
-    // Only allow comparing with expression with the same value type
-    fn greater_equals<O: SqlExpression<S, SqlType = <Self as SqlExpression<S>>::SqlType>>(
-        self,
-        other: O,
-    ) -> SqlConditionGreaterEquals<S, Self, O> {
+    // Only allow comparing with expressions with comparable value types
+    fn greater_equals<O: SqlExpression<S>>(self, other: O) -> SqlConditionGreaterEquals<S, Self, O>
+    where
+        Self::SqlType: SqlComparable<O::SqlType>,
+    {
         SqlConditionGreaterEquals::new(self, other)
     }
 
+    /// Returns a condition which will be true if this expression is between
+    /// the given lower and upper bounds, inclusive.
+    fn between<L: SqlExpression<S>, H: SqlExpression<S>>(
+        self,
+        low: L,
+        high: H,
+    ) -> SqlConditionBetween<S, Self, L, H>
+    where
+        Self::SqlType: SqlComparable<L::SqlType> + SqlComparable<H::SqlType>,
+    {
+        SqlConditionBetween::new(self, low, high)
+    }
+
+    /// Returns a condition which will be true if this expression is not
+    /// between the given lower and upper bounds, inclusive.
+    fn not_between<L: SqlExpression<S>, H: SqlExpression<S>>(
+        self,
+        low: L,
+        high: H,
+    ) -> SqlConditionNotBetween<S, Self, L, H>
+    where
+        Self::SqlType: SqlComparable<L::SqlType> + SqlComparable<H::SqlType>,
+    {
+        SqlConditionNotBetween::new(self, low, high)
+    }
+
     /// Returns an expression which evaluates to the max value of the values
     /// returned from the query.
     fn max(self) -> SqlMax<S, Self> {
         SqlMax::new(self)
     }
+
+    /// Returns an expression which evaluates to the first value returned from
+    /// the query whose position in the ordered values is greater than or equal
+    /// to the given fraction, or to null if all of the values are null.
+    ///
+    /// The fraction should be between 0 and 1, for example a fraction of 0.5
+    /// finds the median.
+    fn percentile_disc<F: SqlExpression<S, SqlType = SqlF64>>(
+        self,
+        fraction: F,
+    ) -> SqlPercentileDisc<S, F, Self>
+    where
+        (
+            <<Self::SqlType as SqlType>::NonNullSqlType as SqlType>::IsNull,
+            TypedFalse,
+        ): TypesEqual,
+    {
+        SqlPercentileDisc::new(fraction, self)
+    }
+
+    /// Returns an expression which evaluates to the greater value out of this
+    /// expression and the given one, ignoring null values.
+    fn greatest<O: SqlExpression<S>>(self, other: O) -> SqlGreatest<S, Self, O>
+    where
+        Self::SqlType: SqlComparable<O::SqlType>,
+    {
+        SqlGreatest::new(self, other)
+    }
+
+    /// Returns an expression which evaluates to the lower value out of this
+    /// expression and the given one, ignoring null values.
+    fn least<O: SqlExpression<S>>(self, other: O) -> SqlLeast<S, Self, O>
+    where
+        Self::SqlType: SqlComparable<O::SqlType>,
+    {
+        SqlLeast::new(self, other)
+    }
 }
 impl<S: SelectableTables, T: SqlExpression<S>> OrderableSqlExpression<S> for T where
     T::SqlType: OrderableSqlType
 {
 }
 
+/// An sql expression which is nullable, which provides some functions to check
+/// if it is null, and to compare it with other expressions in a null-safe way.
+// the `is_*` functions are named after the sql operators that they use.
+#[allow(clippy::wrong_self_convention)]
+pub trait NullableSqlExpression<S: SelectableTables>: SqlExpression<S>
+where
+    Self::SqlType: SqlType<IsNull = TypedTrue>,
+{
+    /// Returns a condition which will be true if this expression is null.
+    fn is_null(self) -> SqlConditionIsNull<S, Self> {
+        SqlConditionIsNull::new(self)
+    }
+
+    /// Returns a condition which will be true if this expression is not null.
+    fn is_not_null(self) -> SqlConditionIsNotNull<S, Self> {
+        SqlConditionIsNotNull::new(self)
+    }
+
+    /// Returns a condition which will be true if this expression is not equal
+    /// to the given one, treating null as a regular comparable value. Unlike
+    /// [`SqlExpression::not_equals`], the condition is never null.
+    fn is_distinct_from<O: SqlExpression<S>>(
+        self,
+        other: O,
+    ) -> SqlConditionIsDistinctFrom<S, Self, O>
+    where
+        Self::SqlType: SqlComparable<O::SqlType>,
+    {
+        SqlConditionIsDistinctFrom::new(self, other)
+    }
+
+    /// Returns a condition which will be true if this expression is equal to
+    /// the given one, treating null as a regular comparable value. Unlike
+    /// [`SqlExpression::equals`], two null values are considered equal, and
+    /// the condition is never null.
+    fn is_not_distinct_from<O: SqlExpression<S>>(
+        self,
+        other: O,
+    ) -> SqlConditionIsNotDistinctFrom<S, Self, O>
+    where
+        Self::SqlType: SqlComparable<O::SqlType>,
+    {
+        SqlConditionIsNotDistinctFrom::new(self, other)
+    }
+
+    /// Returns a condition which will be true if this expression is not null
+    /// and is equal to the given non-nullable expression.
+    fn equals_some<O: SqlExpression<S, SqlType = <Self::SqlType as SqlType>::NonNullSqlType>>(
+        self,
+        other: O,
+    ) -> SqlConditionEq<S, Self, O>
+    where
+        Self::SqlType: SqlComparable<O::SqlType>,
+    {
+        SqlConditionEq::new(self, other)
+    }
+
+    /// Returns an expression which evaluates to this expression if it is not
+    /// null, or to the given expression otherwise.
+    ///
+    /// If the given expression is not nullable, the result is not nullable.
+    fn coalesce<O: SqlExpression<S>>(
+        self,
+        other: O,
+    ) -> SqlCoalesce<S, SqlCoalesceFirstArgument<S, Self>, O>
+    where
+        Self::SqlType: SqlType<NonNullSqlType = <O::SqlType as SqlType>::NonNullSqlType>,
+    {
+        SqlCoalesce::new(self, other)
+    }
+}
+impl<S: SelectableTables, E: SqlExpression<S>> NullableSqlExpression<S> for E where
+    E::SqlType: SqlType<IsNull = TypedTrue>
+{
+}
+
 /// A trait which provides some functions to test if an sql string matches some
 /// certain pattern.
 pub trait LikeableSqlExpression<S: SelectableTables>: SqlExpression<S, SqlType = SqlText> {
@@ -186,10 +479,609 @@ pub trait LikeableSqlExpression<S: SelectableTables>: SqlExpression<S, SqlType =
     ) -> SqlConditionNotLike<S, Self, Rhs> {
         SqlConditionNotLike::new(self, like_pattern)
     }
+
+    /// Returns a condition which will be true if this string matches the given
+    /// pattern, ignoring case.
+    ///
+    /// For more information about the provided pattern, check out the
+    /// documentation on postgres' `ILIKE` operator.
+    fn ilike<Rhs: SqlExpression<S, SqlType = SqlText>>(
+        self,
+        like_pattern: Rhs,
+    ) -> SqlConditionILike<S, Self, Rhs> {
+        SqlConditionILike::new(self, like_pattern)
+    }
+
+    /// Returns a condition which will be true if this string doesn't match the
+    /// given pattern, ignoring case.
+    ///
+    /// For more information about the provided pattern, check out the
+    /// documentation on postgres' `NOT ILIKE` operator.
+    fn not_ilike<Rhs: SqlExpression<S, SqlType = SqlText>>(
+        self,
+        like_pattern: Rhs,
+    ) -> SqlConditionNotILike<S, Self, Rhs> {
+        SqlConditionNotILike::new(self, like_pattern)
+    }
+
+    /// Returns a condition which will be true if this string matches the given
+    /// sql regular expression.
+    ///
+    /// For more information about the provided pattern, check out the
+    /// documentation on sql's `SIMILAR TO` operator.
+    fn similar_to<Rhs: SqlExpression<S, SqlType = SqlText>>(
+        self,
+        similar_to_pattern: Rhs,
+    ) -> SqlConditionSimilarTo<S, Self, Rhs> {
+        SqlConditionSimilarTo::new(self, similar_to_pattern)
+    }
+
+    /// Returns a condition which will be true if this string doesn't match the
+    /// given sql regular expression.
+    ///
+    /// For more information about the provided pattern, check out the
+    /// documentation on sql's `NOT SIMILAR TO` operator.
+    fn not_similar_to<Rhs: SqlExpression<S, SqlType = SqlText>>(
+        self,
+        similar_to_pattern: Rhs,
+    ) -> SqlConditionNotSimilarTo<S, Self, Rhs> {
+        SqlConditionNotSimilarTo::new(self, similar_to_pattern)
+    }
+
+    /// Returns a condition which will be true if this string matches the given
+    /// POSIX regular expression.
+    ///
+    /// For more information about the provided pattern, check out the
+    /// documentation on postgres' `~` operator.
+    fn matches_regex<Rhs: SqlExpression<S, SqlType = SqlText>>(
+        self,
+        regex: Rhs,
+    ) -> SqlConditionMatchesRegex<S, Self, Rhs> {
+        SqlConditionMatchesRegex::new(self, regex)
+    }
+
+    /// Returns a condition which will be true if this string matches the given
+    /// POSIX regular expression, ignoring case.
+    ///
+    /// For more information about the provided pattern, check out the
+    /// documentation on postgres' `~*` operator.
+    fn matches_regex_case_insensitive<Rhs: SqlExpression<S, SqlType = SqlText>>(
+        self,
+        regex: Rhs,
+    ) -> SqlConditionMatchesRegexCaseInsensitive<S, Self, Rhs> {
+        SqlConditionMatchesRegexCaseInsensitive::new(self, regex)
+    }
+
+    /// Returns a condition which will be true if this string doesn't match the
+    /// given POSIX regular expression.
+    ///
+    /// For more information about the provided pattern, check out the
+    /// documentation on postgres' `!~` operator.
+    fn not_matches_regex<Rhs: SqlExpression<S, SqlType = SqlText>>(
+        self,
+        regex: Rhs,
+    ) -> SqlConditionNotMatchesRegex<S, Self, Rhs> {
+        SqlConditionNotMatchesRegex::new(self, regex)
+    }
 }
 
 impl<S: SelectableTables, T: SqlExpression<S, SqlType = SqlText>> LikeableSqlExpression<S> for T {}
 
+/// A text sql expression, which provides some functions for manipulating
+/// strings.
+///
+/// The result of each of these functions, except for the conditions, is null if
+/// this expression or any of the function's arguments is null.
+pub trait TextSqlExpression<S: SelectableTables>: SqlExpression<S>
+where
+    Self::SqlType: SqlType<NonNullSqlType = SqlText>,
+{
+    /// Returns an expression which evaluates to this string converted to lower
+    /// case.
+    fn lower(self) -> SqlLower<S, Self> {
+        SqlLower::new(self)
+    }
+
+    /// Returns an expression which evaluates to this string converted to upper
+    /// case.
+    fn upper(self) -> SqlUpper<S, Self> {
+        SqlUpper::new(self)
+    }
+
+    /// Returns an expression which evaluates to the amount of characters in
+    /// this string.
+    fn length(self) -> SqlLength<S, Self> {
+        SqlLength::new(self)
+    }
+
+    /// Returns an expression which evaluates to this string with the spaces at
+    /// its start and end removed.
+    fn trim(self) -> SqlTrim<S, Self> {
+        SqlTrim::new(self)
+    }
+
+    /// Returns an expression which evaluates to the part of this string which
+    /// starts at the given position, counting from 1, and contains at most the
+    /// given amount of characters.
+    fn substring<F: SqlExpression<S>, L: SqlExpression<S>>(
+        self,
+        start: F,
+        length: L,
+    ) -> SqlSubstring<S, Self, F, L>
+    where
+        F::SqlType: SqlType<NonNullSqlType = SqlI32>,
+        L::SqlType: SqlType<NonNullSqlType = SqlI32>,
+    {
+        SqlSubstring::new(self, start, length)
+    }
+
+    /// Returns an expression which evaluates to this string with all
+    /// occurences of `from` replaced with `to`.
+    fn replace<F: SqlExpression<S>, T: SqlExpression<S>>(
+        self,
+        from: F,
+        to: T,
+    ) -> SqlReplace<S, Self, F, T>
+    where
+        F::SqlType: SqlType<NonNullSqlType = SqlText>,
+        T::SqlType: SqlType<NonNullSqlType = SqlText>,
+    {
+        SqlReplace::new(self, from, to)
+    }
+
+    /// Returns an expression which evaluates to the concatenation of this
+    /// string and the given one.
+    ///
+    /// This will be translated to the `||` operator when converted to sql.
+    fn concat<O: SqlExpression<S>>(self, other: O) -> SqlConcat<S, Self, O>
+    where
+        O::SqlType: SqlType<NonNullSqlType = SqlText>,
+    {
+        SqlConcat::new(self, other)
+    }
+
+    /// Returns an expression which evaluates to the first `n` characters of
+    /// this string.
+    fn left<N: SqlExpression<S>>(self, n: N) -> SqlLeft<S, Self, N>
+    where
+        N::SqlType: SqlType<NonNullSqlType = SqlI32>,
+    {
+        SqlLeft::new(self, n)
+    }
+
+    /// Returns an expression which evaluates to the last `n` characters of
+    /// this string.
+    fn right<N: SqlExpression<S>>(self, n: N) -> SqlRight<S, Self, N>
+    where
+        N::SqlType: SqlType<NonNullSqlType = SqlI32>,
+    {
+        SqlRight::new(self, n)
+    }
+
+    /// Returns a condition which will be true if this string starts with the
+    /// given prefix.
+    fn starts_with<P: SqlExpression<S>>(self, prefix: P) -> SqlStartsWith<S, Self, P>
+    where
+        P::SqlType: SqlType<NonNullSqlType = SqlText>,
+    {
+        SqlStartsWith::new(self, prefix)
+    }
+
+    /// Returns an expression which evaluates to the position of the first
+    /// occurence of the given substring in this string, counting from 1, or to
+    /// 0 if this string doesn't contain it.
+    fn position<Sub: SqlExpression<S>>(self, substring: Sub) -> SqlPosition<S, Sub, Self>
+    where
+        Sub::SqlType: SqlType<NonNullSqlType = SqlText>,
+    {
+        SqlPosition::new(substring, self)
+    }
+
+    /// Returns an expression which evaluates to the concatenation of the
+    /// strings returned from the query, separated by the given delimiter, or
+    /// to null if all of the strings are null.
+    fn string_agg<D: SqlExpression<S, SqlType = SqlText>>(
+        self,
+        delimiter: D,
+    ) -> SqlStringAgg<S, Self, D> {
+        SqlStringAgg::new(self, delimiter)
+    }
+}
+impl<S: SelectableTables, E: SqlExpression<S>> TextSqlExpression<S> for E where
+    E::SqlType: SqlType<NonNullSqlType = SqlText>
+{
+}
+
+/// A numeric sql expression, which provides some math functions.
+///
+/// The result of each of these functions is null if this expression or any of
+/// the function's arguments is null.
+pub trait MathSqlExpression<S: SelectableTables>: SqlExpression<S>
+where
+    <Self::SqlType as SqlType>::NonNullSqlType: MathFunctionSqlType,
+{
+    /// Returns an expression which evaluates to the absolute value of this
+    /// expression.
+    fn abs(self) -> SqlAbs<S, Self> {
+        SqlAbs::new(self)
+    }
+
+    /// Returns an expression which evaluates to the nearest integer which is
+    /// greater than or equal to this expression.
+    fn ceil(self) -> SqlCeil<S, Self> {
+        SqlCeil::new(self)
+    }
+
+    /// Returns an expression which evaluates to the nearest integer which is
+    /// lower than or equal to this expression.
+    fn floor(self) -> SqlFloor<S, Self> {
+        SqlFloor::new(self)
+    }
+
+    /// Returns an expression which evaluates to the square root of this
+    /// expression.
+    fn sqrt(self) -> SqlSqrt<S, Self> {
+        SqlSqrt::new(self)
+    }
+
+    /// Returns an expression which evaluates to this expression raised to the
+    /// power of the given exponent.
+    fn power<X: SqlExpression<S>>(self, exponent: X) -> SqlPower<S, Self, X>
+    where
+        <X::SqlType as SqlType>::NonNullSqlType: MathFunctionSqlType<
+            OutputSqlType = <<Self::SqlType as SqlType>::NonNullSqlType as MathFunctionSqlType>::OutputSqlType,
+        >,
+    {
+        SqlPower::new(self, exponent)
+    }
+
+    /// Returns an expression which evaluates to this numeric expression rounded
+    /// to the given amount of decimal digits.
+    ///
+    /// Only `numeric` expressions can be rounded to a specific amount of
+    /// digits, other number expressions must be cast to `numeric` first.
+    fn round<D: SqlExpression<S>>(self, digits: D) -> SqlRound<S, Self, D>
+    where
+        Self::SqlType: SqlType<NonNullSqlType = SqlNumeric>,
+        D::SqlType: SqlType<NonNullSqlType = SqlI32>,
+    {
+        SqlRound::new(self, digits)
+    }
+}
+impl<S: SelectableTables, E: SqlExpression<S>> MathSqlExpression<S> for E where
+    <E::SqlType as SqlType>::NonNullSqlType: MathFunctionSqlType
+{
+}
+
+/// A date and time sql expression, which provides some functions to operate on
+/// its fields, and to find the difference between it and other date and time
+/// expressions.
+pub trait TemporalSqlExpression<S: SelectableTables>: SqlExpression<S>
+where
+    <Self::SqlType as SqlType>::NonNullSqlType: TemporalSqlType,
+{
+    /// Returns an expression which evaluates to the given field of this
+    /// expression, like its year or its hour.
+    ///
+    /// This will be translated to `DATE_PART('field', expr)` when converted to
+    /// sql.
+    fn extract(self, field: DateTimeField) -> SqlExtract<S, Self> {
+        SqlExtract::new(field, self)
+    }
+
+    /// Returns an expression which evaluates to the difference between this
+    /// expression and the given one, for example, the difference between two
+    /// timestamps is an interval, and the difference between two dates is an
+    /// amount of days.
+    ///
+    /// If any of the expressions is null, the result is null.
+    fn difference<O: SqlExpression<S>>(self, other: O) -> SqlDifference<S, Self, O>
+    where
+        <Self::SqlType as SqlType>::NonNullSqlType:
+            DifferenceableSqlType<<O::SqlType as SqlType>::NonNullSqlType>,
+    {
+        SqlDifference::new(self, other)
+    }
+}
+impl<S: SelectableTables, E: SqlExpression<S>> TemporalSqlExpression<S> for E where
+    <E::SqlType as SqlType>::NonNullSqlType: TemporalSqlType
+{
+}
+
+/// A timestamp sql expression, which provides some functions to operate on
+/// points in time.
+pub trait TimestampSqlExpression<S: SelectableTables>: SqlExpression<S>
+where
+    <Self::SqlType as SqlType>::NonNullSqlType: TimestampSqlType,
+{
+    /// Returns an expression which evaluates to this timestamp truncated to the
+    /// given precision, for example, truncating to [`DateTimeField::Day`] sets
+    /// the time of day to midnight.
+    fn date_trunc(self, field: DateTimeField) -> SqlDateTrunc<S, Self> {
+        SqlDateTrunc::new(field, self)
+    }
+
+    /// Returns an expression which evaluates to the interval between the given
+    /// timestamp and this one, in years, months and days rather than just days.
+    ///
+    /// If any of the timestamps is null, the result is null.
+    fn age<O: SqlExpression<S>>(self, other: O) -> SqlAge<S, Self, O>
+    where
+        Self::SqlType: SqlType<NonNullSqlType = <O::SqlType as SqlType>::NonNullSqlType>,
+    {
+        SqlAge::new(self, other)
+    }
+}
+impl<S: SelectableTables, E: SqlExpression<S>> TimestampSqlExpression<S> for E where
+    <E::SqlType as SqlType>::NonNullSqlType: TimestampSqlType
+{
+}
+
+/// An array sql expression, which provides some functions for checking and
+/// modifying its elements.
+pub trait ArraySqlExpression<S: SelectableTables>: SqlExpression<S>
+where
+    <Self::SqlType as SqlType>::NonNullSqlType: ArraySqlType,
+{
+    /// Returns a condition which checks if this array contains all of the
+    /// elements of the given array.
+    ///
+    /// This will be translated to `expr @> other` when converted to sql.
+    fn contains<O: SqlExpression<S>>(self, other: O) -> SqlArrayContains<S, Self, O>
+    where
+        O::SqlType: SqlType<NonNullSqlType = <Self::SqlType as SqlType>::NonNullSqlType>,
+    {
+        SqlArrayContains::new(self, other)
+    }
+
+    /// Returns a condition which checks if all of the elements of this array
+    /// are contained in the given array.
+    ///
+    /// This will be translated to `expr <@ other` when converted to sql.
+    fn contained_by<O: SqlExpression<S>>(self, other: O) -> SqlArrayContainedBy<S, Self, O>
+    where
+        O::SqlType: SqlType<NonNullSqlType = <Self::SqlType as SqlType>::NonNullSqlType>,
+    {
+        SqlArrayContainedBy::new(self, other)
+    }
+
+    /// Returns a condition which checks if this array and the given array have
+    /// any elements in common.
+    ///
+    /// This will be translated to `expr && other` when converted to sql.
+    fn overlaps<O: SqlExpression<S>>(self, other: O) -> SqlArrayOverlaps<S, Self, O>
+    where
+        O::SqlType: SqlType<NonNullSqlType = <Self::SqlType as SqlType>::NonNullSqlType>,
+    {
+        SqlArrayOverlaps::new(self, other)
+    }
+
+    /// Returns an expression which evaluates to the amount of elements in this
+    /// array.
+    ///
+    /// The result is null if this array is empty or null.
+    fn array_length(self) -> SqlArrayLength<S, Self> {
+        SqlArrayLength::new(self)
+    }
+
+    /// Returns an expression which evaluates to this array with the given
+    /// element appended to its end.
+    ///
+    /// If this array is null, the result is an array containing only the given
+    /// element.
+    fn array_append<E: SqlExpression<S>>(self, element: E) -> SqlArrayAppend<S, Self, E>
+    where
+        (
+            E::SqlType,
+            <<Self::SqlType as SqlType>::NonNullSqlType as ArraySqlType>::ElementSqlType,
+        ): TypesEqual,
+    {
+        SqlArrayAppend::new(self, element)
+    }
+
+    /// Returns a value which expands this array to a set of rows, one for each
+    /// of its elements.
+    ///
+    /// The result can only be used in the list of selected values, in which
+    /// case each row of the source is repeated once for each element of the
+    /// array, and rows whose array is empty or null are omitted. Using it as a
+    /// source to select from is not supported.
+    fn unnest(self) -> SqlUnnest<S, Self> {
+        SqlUnnest::new(self)
+    }
+}
+impl<S: SelectableTables, E: SqlExpression<S>> ArraySqlExpression<S> for E where
+    <E::SqlType as SqlType>::NonNullSqlType: ArraySqlType
+{
+}
+
+/// A binary sql expression, which provides some functions to operate on its
+/// bytes, and to compute digests of them.
+///
+/// The result of each of these functions is null if this expression or any of
+/// the function's arguments is null.
+pub trait ByteaSqlExpression<S: SelectableTables>: SqlExpression<S>
+where
+    Self::SqlType: SqlType<NonNullSqlType = SqlBytea>,
+{
+    /// Returns an expression which evaluates to the amount of bytes in this
+    /// binary value.
+    fn length(self) -> SqlByteaLength<S, Self> {
+        SqlByteaLength::new(self)
+    }
+
+    /// Returns an expression which evaluates to the part of this binary value
+    /// which starts at the given position, counting from 1, and contains at
+    /// most the given amount of bytes.
+    fn substring<F: SqlExpression<S>, L: SqlExpression<S>>(
+        self,
+        start: F,
+        length: L,
+    ) -> SqlByteaSubstring<S, Self, F, L>
+    where
+        F::SqlType: SqlType<NonNullSqlType = SqlI32>,
+        L::SqlType: SqlType<NonNullSqlType = SqlI32>,
+    {
+        SqlByteaSubstring::new(self, start, length)
+    }
+
+    /// Returns an expression which evaluates to the md5 digest of this binary
+    /// value, encoded as hex text.
+    fn md5(self) -> SqlMd5<S, Self> {
+        SqlMd5::new(self)
+    }
+
+    /// Returns an expression which evaluates to the sha224 digest of this
+    /// binary value.
+    fn sha224(self) -> SqlSha224<S, Self> {
+        SqlSha224::new(self)
+    }
+
+    /// Returns an expression which evaluates to the sha256 digest of this
+    /// binary value.
+    fn sha256(self) -> SqlSha256<S, Self> {
+        SqlSha256::new(self)
+    }
+
+    /// Returns an expression which evaluates to the sha384 digest of this
+    /// binary value.
+    fn sha384(self) -> SqlSha384<S, Self> {
+        SqlSha384::new(self)
+    }
+
+    /// Returns an expression which evaluates to the sha512 digest of this
+    /// binary value.
+    fn sha512(self) -> SqlSha512<S, Self> {
+        SqlSha512::new(self)
+    }
+}
+impl<S: SelectableTables, E: SqlExpression<S>> ByteaSqlExpression<S> for E where
+    E::SqlType: SqlType<NonNullSqlType = SqlBytea>
+{
+}
+
+/// A json sql expression, which provides some functions to access its fields
+/// and elements.
+///
+/// The result of each of these functions is null if the accessed field or
+/// element doesn't exist, or if this expression or any of the function's
+/// arguments is null.
+pub trait JsonSqlExpression<S: SelectableTables>: SqlExpression<S>
+where
+    <Self::SqlType as SqlType>::NonNullSqlType: JsonSqlType,
+{
+    /// Returns an expression which evaluates to the field with the given key of
+    /// this json object.
+    ///
+    /// This will be translated to `expr -> key` when converted to sql.
+    fn get<K: SqlExpression<S>>(self, key: K) -> SqlJsonGet<S, Self, K>
+    where
+        K::SqlType: SqlType<NonNullSqlType = SqlText>,
+    {
+        SqlJsonGet::new(self, key)
+    }
+
+    /// Returns an expression which evaluates to the field with the given key of
+    /// this json object, as text.
+    ///
+    /// This will be translated to `expr ->> key` when converted to sql.
+    fn get_text<K: SqlExpression<S>>(self, key: K) -> SqlJsonGetText<S, Self, K>
+    where
+        K::SqlType: SqlType<NonNullSqlType = SqlText>,
+    {
+        SqlJsonGetText::new(self, key)
+    }
+
+    /// Returns an expression which evaluates to the element at the given index
+    /// of this json array, counting from 0. Negative indexes count from the end
+    /// of the array.
+    ///
+    /// This will be translated to `expr -> index` when converted to sql.
+    fn get_index<I: SqlExpression<S>>(self, index: I) -> SqlJsonGetIndex<S, Self, I>
+    where
+        I::SqlType: SqlType<NonNullSqlType = SqlI32>,
+    {
+        SqlJsonGetIndex::new(self, index)
+    }
+
+    /// Returns an expression which evaluates to the element at the given index
+    /// of this json array, as text.
+    ///
+    /// This will be translated to `expr ->> index` when converted to sql.
+    fn get_index_text<I: SqlExpression<S>>(self, index: I) -> SqlJsonGetIndexText<S, Self, I>
+    where
+        I::SqlType: SqlType<NonNullSqlType = SqlI32>,
+    {
+        SqlJsonGetIndexText::new(self, index)
+    }
+
+    /// Returns an expression which evaluates to the value at the given path of
+    /// this json value, where each element of the path is either a field key or
+    /// an array index.
+    ///
+    /// This will be translated to `expr #> path` when converted to sql.
+    fn get_path<P: SqlExpression<S>>(self, path: P) -> SqlJsonGetPath<S, Self, P>
+    where
+        P::SqlType: SqlType<NonNullSqlType = SqlArray<SqlText>>,
+    {
+        SqlJsonGetPath::new(self, path)
+    }
+}
+impl<S: SelectableTables, E: SqlExpression<S>> JsonSqlExpression<S> for E where
+    <E::SqlType as SqlType>::NonNullSqlType: JsonSqlType
+{
+}
+
+/// A `jsonb` sql expression, which provides some functions for checking and
+/// modifying its contents.
+///
+/// The result of each of these functions is null if this expression or any of
+/// the function's arguments is null.
+pub trait JsonbSqlExpression<S: SelectableTables>: SqlExpression<S>
+where
+    Self::SqlType: SqlType<NonNullSqlType = SqlJsonb>,
+{
+    /// Returns a condition which checks if this json value contains the given
+    /// json value at its top level.
+    ///
+    /// This will be translated to `expr @> other` when converted to sql.
+    fn contains<O: SqlExpression<S>>(self, other: O) -> SqlJsonbContains<S, Self, O>
+    where
+        O::SqlType: SqlType<NonNullSqlType = SqlJsonb>,
+    {
+        SqlJsonbContains::new(self, other)
+    }
+
+    /// Returns a condition which checks if the given string exists as a top
+    /// level key of this json object, or as an element of this json array.
+    ///
+    /// This will be translated to `expr ? key` when converted to sql.
+    fn has_key<K: SqlExpression<S>>(self, key: K) -> SqlJsonbHasKey<S, Self, K>
+    where
+        K::SqlType: SqlType<NonNullSqlType = SqlText>,
+    {
+        SqlJsonbHasKey::new(self, key)
+    }
+
+    /// Returns an expression which evaluates to this json value with the value
+    /// at the given path replaced by the given value.
+    fn jsonb_set<P: SqlExpression<S>, V: SqlExpression<S>>(
+        self,
+        path: P,
+        value: V,
+    ) -> SqlJsonbSet<S, Self, P, V>
+    where
+        P::SqlType: SqlType<NonNullSqlType = SqlArray<SqlText>>,
+        V::SqlType: SqlType<NonNullSqlType = SqlJsonb>,
+    {
+        SqlJsonbSet::new(self, path, value)
+    }
+}
+impl<S: SelectableTables, E: SqlExpression<S>> JsonbSqlExpression<S> for E where
+    E::SqlType: SqlType<NonNullSqlType = SqlJsonb>
+{
+}
+
 /// An sql expression which is averageable, which means that we can find the
 /// average value of it.
 pub trait AverageableSqlExpression<S: SelectableTables>: SqlExpression<S>
@@ -224,6 +1116,31 @@ impl<S: SelectableTables, E: SqlExpression<S>> SummableSqlExpression<S> for E wh
 {
 }
 
+/// An sql expression whose statistical properties, like its standard deviation
+/// and variance, can be computed.
+pub trait StatisticalSqlExpression<S: SelectableTables>: SqlExpression<S>
+where
+    Self::SqlType: StatisticalSqlType,
+{
+    /// Returns an expression which evaluates to the sample standard deviation
+    /// of the items returned from the query, or to null if there are less than
+    /// 2 non-null items.
+    fn stddev(self) -> SqlStddev<S, Self> {
+        SqlStddev::new(self)
+    }
+
+    /// Returns an expression which evaluates to the sample variance of the
+    /// items returned from the query, or to null if there are less than 2
+    /// non-null items.
+    fn variance(self) -> SqlVariance<S, Self> {
+        SqlVariance::new(self)
+    }
+}
+impl<S: SelectableTables, E: SqlExpression<S>> StatisticalSqlExpression<S> for E where
+    E::SqlType: StatisticalSqlType
+{
+}
+
 macro_rules! define_expression_operator_trait {
     {$trait_name: ident, $sql_type_marker: ident, $expr_type: ident, $fn_name: ident} => {
         pub trait $trait_name<S: SelectableTables, Rhs: SqlExpression<S>>:
@@ -249,6 +1166,28 @@ define_expression_operator_trait! {AddableSqlExpression, SqlAdd, SqlAddition, ad
 define_expression_operator_trait! {SubtractableSqlExpression, SqlSubtract, SqlSubtraction, subtract}
 define_expression_operator_trait! {MultipliableSqlExpression, SqlMultiply, SqlMultiplication, multiply}
 define_expression_operator_trait! {DivisibleSqlExpression, SqlDivide, SqlDivision, divide}
+define_expression_operator_trait! {ModulableSqlExpression, SqlMod, SqlModulo, modulo}
+define_expression_operator_trait! {BitwiseAndableSqlExpression, SqlBitwise, SqlBitwiseAnd, bitwise_and}
+define_expression_operator_trait! {BitwiseOrableSqlExpression, SqlBitwise, SqlBitwiseOr, bitwise_or}
+define_expression_operator_trait! {BitwiseXorableSqlExpression, SqlBitwise, SqlBitwiseXor, bitwise_xor}
+define_expression_operator_trait! {LeftShiftableSqlExpression, SqlShift, SqlShiftLeft, shift_left}
+define_expression_operator_trait! {RightShiftableSqlExpression, SqlShift, SqlShiftRight, shift_right}
+
+/// An sql expression whose bits can be inverted.
+pub trait BitwiseNotableSqlExpression<S: SelectableTables>: SqlExpression<S>
+where
+    Self::SqlType: SqlBitwise<Self::SqlType>,
+{
+    /// Returns an expression which evaluates to this expression with all of
+    /// its bits inverted.
+    fn bitwise_not(self) -> SqlBitwiseNot<S, Self> {
+        SqlBitwiseNot::new(self)
+    }
+}
+impl<S: SelectableTables, E: SqlExpression<S>> BitwiseNotableSqlExpression<S> for E where
+    E::SqlType: SqlBitwise<E::SqlType>
+{
+}
 
 macro_rules! define_boolean_expression_operator_trait {
     {$trait_name: ident, $expr_type: ident, $fn_name: ident} => {
@@ -299,7 +1238,108 @@ macro_rules! impl_primitive_expression{
     }
 }
 
-impl_primitive_expression! {bool, i16, i32, i64, f32, f64}
+impl_primitive_expression! {
+    bool,
+    i16,
+    i32,
+    i64,
+    f32,
+    f64,
+    String,
+    Decimal,
+    Interval,
+    serde_json::Value,
+    Vec<u8>,
+    Bytea
+}
+
+#[cfg(feature = "chrono")]
+impl_primitive_expression! {
+    chrono::NaiveDateTime,
+    chrono::DateTime<chrono::Utc>,
+    chrono::DateTime<chrono::FixedOffset>,
+    chrono::DateTime<chrono::Local>,
+    chrono::NaiveDate,
+    chrono::NaiveTime
+}
+
+#[cfg(feature = "uuid")]
+impl_primitive_expression! {uuid::Uuid}
+
+#[cfg(feature = "time")]
+impl_primitive_expression! {time::PrimitiveDateTime, time::OffsetDateTime, time::Date, time::Time}
+
+/// Implements [`SqlExpression`] for json wrapper types, using their value as a
+/// bound parameter.
+macro_rules! impl_json_wrapper_expression {
+    { $($wrapper: ident),+ } => {
+        $(
+            impl<S: SelectableTables, T: Serialize + Debug + Sync> SqlExpression<S>
+                for $wrapper<T>
+            {
+                type RustType = $wrapper<T>;
+                type SqlType = <$wrapper<T> as IntoSqlType>::SqlType;
+
+                const IS_AGGREGATE: bool = false;
+
+                fn write_sql_string<'s, 'a>(
+                    &'s self,
+                    f: &mut String,
+                    parameter_binder: &mut ParameterBinder<'a>,
+                ) -> std::fmt::Result
+                where
+                    's: 'a,
+                {
+                    write!(f, "{}", parameter_binder.bind_parameter(self))
+                }
+            }
+        )+
+    };
+}
+
+impl_json_wrapper_expression! {Json, Jsonb}
+
+impl<S: SelectableTables, T: IntoSqlType + ToSql + Sync> SqlExpression<S> for Option<T>
+where
+    (<T::SqlType as SqlType>::IsNull, TypedFalse): TypesEqual,
+{
+    type RustType = Option<T>;
+    type SqlType = SqlOption<T::SqlType>;
+
+    const IS_AGGREGATE: bool = false;
+
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        write!(f, "{}", parameter_binder.bind_parameter(self))
+    }
+}
+
+impl<S: SelectableTables, T: IntoSqlType + ToSql + Sync> SqlExpression<S> for Vec<T>
+where
+    T::SqlType: ArrayElementSqlType,
+{
+    type RustType = Vec<T>;
+    type SqlType = SqlArray<T::SqlType>;
+
+    const IS_AGGREGATE: bool = false;
+
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        write!(f, "{}", parameter_binder.bind_parameter(self))
+    }
+}
 
 impl<'b, S: SelectableTables> SqlExpression<S> for &'b str {
     type RustType = &'b str;
@@ -318,3 +1358,21 @@ impl<'b, S: SelectableTables> SqlExpression<S> for &'b str {
         write!(f, "{}", parameter_binder.bind_parameter(self))
     }
 }
+
+impl<'b, S: SelectableTables> SqlExpression<S> for &'b [u8] {
+    type RustType = &'b [u8];
+    type SqlType = SqlBytea;
+
+    const IS_AGGREGATE: bool = false;
+
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        write!(f, "{}", parameter_binder.bind_parameter(self))
+    }
+}
@@ -1,5 +1,11 @@
+use std::marker::PhantomData;
+
 use super::ParameterBinder;
-use crate::{statements::EmptyInsertStatement, Table};
+use crate::{
+    statements::{EmptyInsertStatement, SelectStatement},
+    util::{TypedBool, TypedFalse, TypedTrue, TypesEqual},
+    Table,
+};
 
 /// A record type which can be inserted into the database.
 #[async_trait::async_trait]
@@ -24,8 +30,118 @@ pub trait Insertable: Sized {
     where
         's: 'a;
 
+    /// Writes the values clause of this insertable.
+    /// For example, in the query:
+    /// `INSERT INTO person(name,age) values('James', 29)`
+    /// This represents the `values('James', 29)` part.
+    fn write_values_clause<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        use std::fmt::Write;
+
+        write!(f, "VALUES(")?;
+        self.write_values(f, parameter_binder)?;
+        write!(f, ")")
+    }
+
     /// Returns an insert statement for this record.
     fn insert(self) -> EmptyInsertStatement<Self> {
         EmptyInsertStatement::new(self)
     }
 }
+
+/// An insertable which inserts the records returned by some select statement
+/// into a table.
+///
+/// The `WithId` generic determines whether the `id` column of the table is
+/// also inserted, or whether it is left for the database to generate.
+///
+/// This insertable shouldn't be used directly, you should instead use the
+/// [`TableMarker::insert_select`] or [`TableMarker::insert_select_with_id`]
+/// functions.
+///
+/// [`TableMarker::insert_select`]: crate::sql::TableMarker::insert_select
+/// [`TableMarker::insert_select_with_id`]: crate::sql::TableMarker::insert_select_with_id
+pub struct SelectStatementInsertable<T: Table, S: SelectStatement, WithId: TypedBool> {
+    statement: S,
+    phantom: PhantomData<(T, WithId)>,
+}
+
+impl<T: Table, S: SelectStatement> SelectStatementInsertable<T, S, TypedFalse>
+where
+    (<S as SelectStatement>::OutputFields, T::FieldsWithoutId): TypesEqual,
+{
+    /// Creates a new insertable which inserts the records returned by the
+    /// given select statement, leaving the `id` column for the database to
+    /// generate.
+    pub fn new(statement: S) -> Self {
+        Self {
+            statement,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Table, S: SelectStatement> SelectStatementInsertable<T, S, TypedTrue>
+where
+    (<S as SelectStatement>::OutputFields, T::Fields): TypesEqual,
+{
+    /// Creates a new insertable which inserts the records returned by the
+    /// given select statement, including their `id` column.
+    pub fn new_with_id(statement: S) -> Self {
+        Self {
+            statement,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Table, S: SelectStatement, WithId: TypedBool> Insertable
+    for SelectStatementInsertable<T, S, WithId>
+{
+    type Table = T;
+
+    fn write_value_names(&self, f: &mut String) -> std::fmt::Result {
+        let mut is_first = true;
+        for field in T::FIELDS {
+            if field.is_primary_key && !WithId::VALUE {
+                continue;
+            }
+            if !is_first {
+                f.push(',');
+            }
+            f.push_str(field.name);
+            is_first = false;
+        }
+        Ok(())
+    }
+
+    fn write_values<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        <S as SelectStatement>::write_sql_string(&self.statement, f, parameter_binder)
+    }
+
+    fn write_values_clause<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        // the select statement itself is the values clause, there is no need
+        // for the `VALUES` keyword.
+        self.write_values(f, parameter_binder)
+    }
+}
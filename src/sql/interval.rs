@@ -0,0 +1,116 @@
+use std::error::Error;
+
+use bytes::{Buf, BufMut, BytesMut};
+use deadpool_postgres::tokio_postgres::types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+/// An sql interval, which represents a span of time.
+///
+/// The months, days and microseconds of the interval are stored separately,
+/// because the length of a month or a day may vary, so they can't be converted
+/// to each other without knowing the point in time that the interval is
+/// relative to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Interval {
+    /// The amount of months in this interval.
+    pub months: i32,
+
+    /// The amount of days in this interval, in addition to its months.
+    pub days: i32,
+
+    /// The amount of microseconds in this interval, in addition to its months
+    /// and days.
+    pub microseconds: i64,
+}
+impl Interval {
+    /// Creates a new interval with the given amount of months, days and
+    /// microseconds.
+    pub fn new(months: i32, days: i32, microseconds: i64) -> Self {
+        Self {
+            months,
+            days,
+            microseconds,
+        }
+    }
+
+    /// Creates a new interval of the given amount of months.
+    pub fn months(months: i32) -> Self {
+        Self::new(months, 0, 0)
+    }
+
+    /// Creates a new interval of the given amount of days.
+    pub fn days(days: i32) -> Self {
+        Self::new(0, days, 0)
+    }
+
+    /// Creates a new interval of the given amount of microseconds.
+    pub fn microseconds(microseconds: i64) -> Self {
+        Self::new(0, 0, microseconds)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::Duration> for Interval {
+    /// Converts the duration to an interval of microseconds, truncating any
+    /// nanoseconds, and saturating if it doesn't fit in an `i64`.
+    fn from(duration: chrono::Duration) -> Self {
+        Self::microseconds(duration.num_microseconds().unwrap_or(
+            if duration < chrono::Duration::zero() {
+                i64::MIN
+            } else {
+                i64::MAX
+            },
+        ))
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Duration> for Interval {
+    /// Converts the duration to an interval of microseconds, truncating any
+    /// nanoseconds, and saturating if it doesn't fit in an `i64`.
+    fn from(duration: time::Duration) -> Self {
+        let microseconds = duration.whole_microseconds();
+        Self::microseconds(microseconds.clamp(i64::MIN.into(), i64::MAX.into()) as i64)
+    }
+}
+
+impl<'a> FromSql<'a> for Interval {
+    fn from_sql(_ty: &Type, mut raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        if raw.len() != 16 {
+            return Err("invalid interval length".into());
+        }
+
+        let microseconds = raw.get_i64();
+        let days = raw.get_i32();
+        let months = raw.get_i32();
+
+        Ok(Self {
+            months,
+            days,
+            microseconds,
+        })
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INTERVAL
+    }
+}
+
+impl ToSql for Interval {
+    fn to_sql(
+        &self,
+        _ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        out.put_i64(self.microseconds);
+        out.put_i32(self.days);
+        out.put_i32(self.months);
+
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INTERVAL
+    }
+
+    to_sql_checked!();
+}
@@ -0,0 +1,90 @@
+use std::{
+    error::Error,
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+};
+
+use bytes::BytesMut;
+use deadpool_postgres::tokio_postgres::types::{
+    self as postgres_types, to_sql_checked, FromSql, IsNull, ToSql, Type,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Defines a wrapper around a serializable type which stores it in the database
+/// as a json value of some sql json type.
+macro_rules! define_json_wrapper {
+    {$(#[$attr: meta])* $type_name: ident, $postgres_type: ident} => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $type_name<T>(pub T);
+
+        impl<T> $type_name<T> {
+            /// Returns the wrapped value.
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+        }
+
+        impl<T> Deref for $type_name<T> {
+            type Target = T;
+
+            fn deref(&self) -> &T {
+                &self.0
+            }
+        }
+
+        impl<T> DerefMut for $type_name<T> {
+            fn deref_mut(&mut self) -> &mut T {
+                &mut self.0
+            }
+        }
+
+        impl<T> From<T> for $type_name<T> {
+            fn from(value: T) -> Self {
+                Self(value)
+            }
+        }
+
+        impl<'a, T: DeserializeOwned> FromSql<'a> for $type_name<T> {
+            fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+                let postgres_types::Json(value) = postgres_types::Json::<T>::from_sql(ty, raw)?;
+                Ok(Self(value))
+            }
+
+            fn accepts(ty: &Type) -> bool {
+                *ty == Type::$postgres_type
+            }
+        }
+
+        impl<T: Serialize + Debug> ToSql for $type_name<T> {
+            fn to_sql(
+                &self,
+                ty: &Type,
+                out: &mut BytesMut,
+            ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+                postgres_types::Json(&self.0).to_sql(ty, out)
+            }
+
+            fn accepts(ty: &Type) -> bool {
+                *ty == Type::$postgres_type
+            }
+
+            to_sql_checked!();
+        }
+    };
+}
+
+define_json_wrapper! {
+    /// A wrapper around a serializable value which stores it in the database as
+    /// an sql `json` value.
+    Json, JSON
+}
+
+define_json_wrapper! {
+    /// A wrapper around a serializable value which stores it in the database as
+    /// an sql `jsonb` value.
+    ///
+    /// A [`serde_json::Value`] can also be used directly as a `jsonb` value,
+    /// without this wrapper.
+    Jsonb, JSONB
+}
@@ -1,11 +1,14 @@
 //! Sql logic encoded in rust's type system.
 
 mod bound_parameters;
+mod bytea;
 mod condition;
 mod expr;
 mod fields_list;
 mod from_query_result;
 mod insertable;
+mod interval;
+mod json;
 mod migration;
 mod operators;
 mod selectable_tables;
@@ -18,11 +21,14 @@ mod unique_constraint;
 mod update_set;
 
 pub use bound_parameters::*;
+pub use bytea::*;
 pub use condition::*;
 pub use expr::*;
 pub use fields_list::*;
 pub use from_query_result::*;
 pub use insertable::*;
+pub use interval::*;
+pub use json::*;
 pub use migration::*;
 pub use operators::*;
 pub use selectable_tables::*;
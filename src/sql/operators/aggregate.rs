@@ -2,46 +2,179 @@ use std::{fmt::Write, marker::PhantomData};
 
 use rust_decimal::Decimal;
 
-use crate::sql::{
-    AverageableSqlType, OrderableSqlType, ParameterBinder, SelectableTables, SqlExpression, SqlI64,
-    SqlNumeric, SqlType, SummableSqlType,
+use crate::{
+    sql::{
+        ArrayElementSqlType, AverageableSqlType, ContinuousPercentileSqlType, OrderableSqlType,
+        ParameterBinder, SelectableTables, SqlArray, SqlBool, SqlExpression, SqlF64, SqlI64,
+        SqlNumeric, SqlOption, SqlText, SqlType, StatisticalSqlType, SummableSqlType,
+    },
+    statements::{AscendingOrder, DescendingOrder, Ordering},
+    util::{TypedBool, TypedFalse, TypedTrue, TypesEqual},
 };
 
-pub struct SqlCountRows;
+/// An aggregate sql expression, which evaluates to a single value computed from
+/// the values of all rows in a group.
+///
+/// The aggregate can be modified to only consider distinct values, to consider
+/// the values in a specific order, or to only consider rows which match some
+/// condition, using the [`AggregateDistinct`], [`AggregateOrderBy`] and
+/// [`AggregateFilter`] traits.
+pub trait AggregateSqlExpression<S: SelectableTables>: SqlExpression<S> {
+    /// Does this aggregate only consider distinct values?
+    type HasDistinct: TypedBool;
 
-impl<S: SelectableTables> SqlExpression<S> for SqlCountRows {
-    type RustType = i64;
-    type SqlType = SqlI64;
+    /// Does this aggregate consider the values in a specific order?
+    type HasOrderBy: TypedBool;
 
-    const IS_AGGREGATE: bool = true;
+    /// Does this aggregate only consider rows which match some condition?
+    type HasFilter: TypedBool;
 
-    fn write_sql_string<'s, 'a>(
+    /// Does this aggregate evaluate to null when there are no rows to
+    /// aggregate, for example when none of the rows match its filter?
+    type IsNullWithoutRows: TypedBool;
+
+    /// Writes the name of the aggregate function.
+    fn write_function_name(&self, f: &mut String) -> std::fmt::Result;
+
+    /// Writes the arguments of the aggregate function, including the
+    /// `DISTINCT` and `ORDER BY` modifiers of the arguments, if any.
+    fn write_arguments<'s, 'a>(
         &'s self,
         f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a;
+
+    /// Writes the `WITHIN GROUP` clause of the aggregate, if it is an ordered
+    /// set aggregate.
+    fn write_within_group_clause<'s, 'a>(
+        &'s self,
+        _f: &mut String,
         _parameter_binder: &mut ParameterBinder<'a>,
     ) -> std::fmt::Result
     where
         's: 'a,
     {
-        write!(f, "COUNT(*)")
+        Ok(())
     }
-}
 
-pub struct SqlCount<S: SelectableTables, E: SqlExpression<S>> {
-    expr: E,
-    phantom: PhantomData<S>,
+    /// Writes the `FILTER` clause of the aggregate, if any.
+    fn write_filter_clause<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a;
+
+    /// Writes the aggregate function call, including all of its modifiers.
+    fn write_aggregate_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        self.write_function_name(f)?;
+        write!(f, "(")?;
+        self.write_arguments(f, parameter_binder)?;
+        write!(f, ")")?;
+        self.write_within_group_clause(f, parameter_binder)?;
+        self.write_filter_clause(f, parameter_binder)
+    }
 }
 
-impl<S: SelectableTables, E: SqlExpression<S>> SqlCount<S, E> {
-    pub fn new(expr: E) -> Self {
-        Self {
-            expr,
-            phantom: PhantomData,
+/// Defines an aggregate expression struct, which aggregates the values of a
+/// single expression.
+macro_rules! define_one_expr_arg_aggregate {
+    {@is_null_without_rows} => { TypedTrue };
+    {@is_null_without_rows $is_null_without_rows: ty} => { $is_null_without_rows };
+    {
+        $type_name: ident, $function_name: literal, $rust_type: ty, $sql_type: ty
+        $(, is_null_without_rows = $is_null_without_rows: ty)?
+        $(, where $bounded_type: ty: $bound: path)?
+    } => {
+        pub struct $type_name<S: SelectableTables, E: SqlExpression<S>>
+        $(where $bounded_type: $bound)?
+        {
+            expr: E,
+            phantom: PhantomData<S>,
         }
-    }
+
+        impl<S: SelectableTables, E: SqlExpression<S>> $type_name<S, E>
+        $(where $bounded_type: $bound)?
+        {
+            pub fn new(expr: E) -> Self {
+                Self {
+                    expr,
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<S: SelectableTables, E: SqlExpression<S>> SqlExpression<S> for $type_name<S, E>
+        $(where $bounded_type: $bound)?
+        {
+            type RustType = $rust_type;
+            type SqlType = $sql_type;
+
+            const IS_AGGREGATE: bool = true;
+
+            fn write_sql_string<'s, 'a>(
+                &'s self,
+                f: &mut String,
+                parameter_binder: &mut ParameterBinder<'a>,
+            ) -> std::fmt::Result
+            where
+                's: 'a,
+            {
+                self.write_aggregate_sql_string(f, parameter_binder)
+            }
+        }
+
+        impl<S: SelectableTables, E: SqlExpression<S>> AggregateSqlExpression<S> for $type_name<S, E>
+        $(where $bounded_type: $bound)?
+        {
+            type HasDistinct = TypedFalse;
+            type HasFilter = TypedFalse;
+            type HasOrderBy = TypedFalse;
+            type IsNullWithoutRows =
+                define_one_expr_arg_aggregate!(@is_null_without_rows $($is_null_without_rows)?);
+
+            fn write_function_name(&self, f: &mut String) -> std::fmt::Result {
+                write!(f, $function_name)
+            }
+
+            fn write_arguments<'s, 'a>(
+                &'s self,
+                f: &mut String,
+                parameter_binder: &mut ParameterBinder<'a>,
+            ) -> std::fmt::Result
+            where
+                's: 'a,
+            {
+                self.expr.write_sql_string(f, parameter_binder)
+            }
+
+            fn write_filter_clause<'s, 'a>(
+                &'s self,
+                _f: &mut String,
+                _parameter_binder: &mut ParameterBinder<'a>,
+            ) -> std::fmt::Result
+            where
+                's: 'a,
+            {
+                Ok(())
+            }
+        }
+    };
 }
 
-impl<S: SelectableTables, E: SqlExpression<S>> SqlExpression<S> for SqlCount<S, E> {
+pub struct SqlCountRows;
+
+impl<S: SelectableTables> SqlExpression<S> for SqlCountRows {
     type RustType = i64;
     type SqlType = SqlI64;
 
@@ -55,39 +188,118 @@ impl<S: SelectableTables, E: SqlExpression<S>> SqlExpression<S> for SqlCount<S,
     where
         's: 'a,
     {
-        write!(f, "COUNT(")?;
-        self.expr.write_sql_string(f, parameter_binder)?;
-        write!(f, ")")?;
+        <Self as AggregateSqlExpression<S>>::write_aggregate_sql_string(self, f, parameter_binder)
+    }
+}
+
+impl<S: SelectableTables> AggregateSqlExpression<S> for SqlCountRows {
+    // `COUNT(*)` has no arguments, so it can't be distinct or ordered.
+    type HasDistinct = TypedTrue;
+    type HasFilter = TypedFalse;
+    type HasOrderBy = TypedTrue;
+    type IsNullWithoutRows = TypedFalse;
+
+    fn write_function_name(&self, f: &mut String) -> std::fmt::Result {
+        write!(f, "COUNT")
+    }
+
+    fn write_arguments<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        _parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        write!(f, "*")
+    }
+
+    fn write_filter_clause<'s, 'a>(
+        &'s self,
+        _f: &mut String,
+        _parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
         Ok(())
     }
 }
 
-pub struct SqlAverage<S: SelectableTables, E: SqlExpression<S>>
-where
-    E::SqlType: AverageableSqlType,
+define_one_expr_arg_aggregate! {SqlCount, "COUNT", i64, SqlI64, is_null_without_rows = TypedFalse}
+define_one_expr_arg_aggregate! {
+    SqlAverage, "AVG", Decimal, SqlNumeric,
+    where E::SqlType: AverageableSqlType
+}
+define_one_expr_arg_aggregate! {
+    SqlSum,
+    "SUM",
+    <<E::SqlType as SummableSqlType>::OutputSqlType as SqlType>::RustType,
+    <E::SqlType as SummableSqlType>::OutputSqlType,
+    where E::SqlType: SummableSqlType
+}
+define_one_expr_arg_aggregate! {SqlMax, "MAX", E::RustType, E::SqlType, where E::SqlType: OrderableSqlType}
+define_one_expr_arg_aggregate! {SqlMin, "MIN", E::RustType, E::SqlType, where E::SqlType: OrderableSqlType}
+define_one_expr_arg_aggregate! {
+    SqlArrayAgg, "ARRAY_AGG", Vec<E::RustType>, SqlArray<E::SqlType>,
+    where E::SqlType: ArrayElementSqlType
+}
+define_one_expr_arg_aggregate! {
+    SqlBoolAnd, "BOOL_AND", Option<bool>, SqlOption<SqlBool>,
+    where E::SqlType: SqlType<NonNullSqlType = SqlBool>
+}
+define_one_expr_arg_aggregate! {
+    SqlBoolOr, "BOOL_OR", Option<bool>, SqlOption<SqlBool>,
+    where E::SqlType: SqlType<NonNullSqlType = SqlBool>
+}
+define_one_expr_arg_aggregate! {
+    SqlStddev,
+    "STDDEV",
+    Option<<<E::SqlType as StatisticalSqlType>::OutputSqlType as SqlType>::RustType>,
+    SqlOption<<E::SqlType as StatisticalSqlType>::OutputSqlType>,
+    where E::SqlType: StatisticalSqlType
+}
+define_one_expr_arg_aggregate! {
+    SqlVariance,
+    "VARIANCE",
+    Option<<<E::SqlType as StatisticalSqlType>::OutputSqlType as SqlType>::RustType>,
+    SqlOption<<E::SqlType as StatisticalSqlType>::OutputSqlType>,
+    where E::SqlType: StatisticalSqlType
+}
+
+pub struct SqlStringAgg<
+    S: SelectableTables,
+    E: SqlExpression<S>,
+    D: SqlExpression<S, SqlType = SqlText>,
+> where
+    E::SqlType: SqlType<NonNullSqlType = SqlText>,
 {
     expr: E,
+    delimiter: D,
     phantom: PhantomData<S>,
 }
 
-impl<S: SelectableTables, E: SqlExpression<S>> SqlAverage<S, E>
+impl<S: SelectableTables, E: SqlExpression<S>, D: SqlExpression<S, SqlType = SqlText>>
+    SqlStringAgg<S, E, D>
 where
-    E::SqlType: AverageableSqlType,
+    E::SqlType: SqlType<NonNullSqlType = SqlText>,
 {
-    pub fn new(expr: E) -> Self {
+    pub fn new(expr: E, delimiter: D) -> Self {
         Self {
             expr,
+            delimiter,
             phantom: PhantomData,
         }
     }
 }
 
-impl<S: SelectableTables, E: SqlExpression<S>> SqlExpression<S> for SqlAverage<S, E>
+impl<S: SelectableTables, E: SqlExpression<S>, D: SqlExpression<S, SqlType = SqlText>>
+    SqlExpression<S> for SqlStringAgg<S, E, D>
 where
-    E::SqlType: AverageableSqlType,
+    E::SqlType: SqlType<NonNullSqlType = SqlText>,
 {
-    type RustType = Decimal;
-    type SqlType = SqlNumeric;
+    type RustType = Option<String>;
+    type SqlType = SqlOption<SqlText>;
 
     const IS_AGGREGATE: bool = true;
 
@@ -99,39 +311,195 @@ where
     where
         's: 'a,
     {
-        write!(f, "AVG(")?;
-        self.expr.write_sql_string(f, parameter_binder)?;
-        write!(f, ")")?;
-        Ok(())
+        self.write_aggregate_sql_string(f, parameter_binder)
     }
 }
 
-pub struct SqlSum<S: SelectableTables, E: SqlExpression<S>>
+impl<S: SelectableTables, E: SqlExpression<S>, D: SqlExpression<S, SqlType = SqlText>>
+    AggregateSqlExpression<S> for SqlStringAgg<S, E, D>
 where
-    E::SqlType: SummableSqlType,
+    E::SqlType: SqlType<NonNullSqlType = SqlText>,
 {
-    expr: E,
-    phantom: PhantomData<S>,
+    type HasDistinct = TypedFalse;
+    type HasFilter = TypedFalse;
+    type HasOrderBy = TypedFalse;
+    type IsNullWithoutRows = TypedTrue;
+
+    fn write_function_name(&self, f: &mut String) -> std::fmt::Result {
+        write!(f, "STRING_AGG")
+    }
+
+    fn write_arguments<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        self.expr.write_sql_string(f, parameter_binder)?;
+        write!(f, ", ")?;
+        self.delimiter.write_sql_string(f, parameter_binder)
+    }
+
+    fn write_filter_clause<'s, 'a>(
+        &'s self,
+        _f: &mut String,
+        _parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        Ok(())
+    }
 }
 
-impl<S: SelectableTables, E: SqlExpression<S>> SqlSum<S, E>
-where
-    E::SqlType: SummableSqlType,
-{
-    pub fn new(expr: E) -> Self {
-        Self {
-            expr,
-            phantom: PhantomData,
+/// Defines an ordered set aggregate expression struct, which finds a percentile
+/// of the values of a single expression.
+macro_rules! define_percentile_aggregate {
+    {
+        $type_name: ident, $function_name: literal, $rust_type: ty, $sql_type: ty,
+        where $($bounded_type: ty: $bound: path),+
+    } => {
+        pub struct $type_name<S: SelectableTables, F: SqlExpression<S, SqlType = SqlF64>, E: SqlExpression<S>>
+        where
+            $($bounded_type: $bound,)+
+        {
+            fraction: F,
+            expr: E,
+            phantom: PhantomData<S>,
         }
-    }
+
+        impl<S: SelectableTables, F: SqlExpression<S, SqlType = SqlF64>, E: SqlExpression<S>>
+            $type_name<S, F, E>
+        where
+            $($bounded_type: $bound,)+
+        {
+            pub fn new(fraction: F, expr: E) -> Self {
+                Self {
+                    fraction,
+                    expr,
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<S: SelectableTables, F: SqlExpression<S, SqlType = SqlF64>, E: SqlExpression<S>>
+            SqlExpression<S> for $type_name<S, F, E>
+        where
+            $($bounded_type: $bound,)+
+        {
+            type RustType = $rust_type;
+            type SqlType = $sql_type;
+
+            const IS_AGGREGATE: bool = true;
+
+            fn write_sql_string<'s, 'a>(
+                &'s self,
+                f: &mut String,
+                parameter_binder: &mut ParameterBinder<'a>,
+            ) -> std::fmt::Result
+            where
+                's: 'a,
+            {
+                self.write_aggregate_sql_string(f, parameter_binder)
+            }
+        }
+
+        impl<S: SelectableTables, F: SqlExpression<S, SqlType = SqlF64>, E: SqlExpression<S>>
+            AggregateSqlExpression<S> for $type_name<S, F, E>
+        where
+            $($bounded_type: $bound,)+
+        {
+            // ordered set aggregates can't be distinct, and their values are
+            // already ordered by the `WITHIN GROUP` clause.
+            type HasDistinct = TypedTrue;
+            type HasFilter = TypedFalse;
+            type HasOrderBy = TypedTrue;
+            type IsNullWithoutRows = TypedTrue;
+
+            fn write_function_name(&self, f: &mut String) -> std::fmt::Result {
+                write!(f, $function_name)
+            }
+
+            fn write_arguments<'s, 'a>(
+                &'s self,
+                f: &mut String,
+                parameter_binder: &mut ParameterBinder<'a>,
+            ) -> std::fmt::Result
+            where
+                's: 'a,
+            {
+                // the fraction must be explicitly cast, because these functions
+                // are overloaded to also accept an array of fractions, so
+                // postgres can't infer the type of a bound parameter.
+                write!(f, "CAST(")?;
+                self.fraction.write_sql_string(f, parameter_binder)?;
+                write!(f, " AS double precision)")
+            }
+
+            fn write_within_group_clause<'s, 'a>(
+                &'s self,
+                f: &mut String,
+                parameter_binder: &mut ParameterBinder<'a>,
+            ) -> std::fmt::Result
+            where
+                's: 'a,
+            {
+                write!(f, " WITHIN GROUP (ORDER BY ")?;
+                self.expr.write_sql_string(f, parameter_binder)?;
+                write!(f, ")")
+            }
+
+            fn write_filter_clause<'s, 'a>(
+                &'s self,
+                _f: &mut String,
+                _parameter_binder: &mut ParameterBinder<'a>,
+            ) -> std::fmt::Result
+            where
+                's: 'a,
+            {
+                Ok(())
+            }
+        }
+    };
 }
 
-impl<S: SelectableTables, E: SqlExpression<S>> SqlExpression<S> for SqlSum<S, E>
-where
-    E::SqlType: SummableSqlType,
+define_percentile_aggregate! {
+    SqlPercentileCont,
+    "PERCENTILE_CONT",
+    Option<<<E::SqlType as ContinuousPercentileSqlType>::OutputSqlType as SqlType>::RustType>,
+    SqlOption<<E::SqlType as ContinuousPercentileSqlType>::OutputSqlType>,
+    where E::SqlType: ContinuousPercentileSqlType
+}
+define_percentile_aggregate! {
+    SqlPercentileDisc,
+    "PERCENTILE_DISC",
+    Option<<<E::SqlType as SqlType>::NonNullSqlType as SqlType>::RustType>,
+    SqlOption<<E::SqlType as SqlType>::NonNullSqlType>,
+    where
+        E::SqlType: OrderableSqlType,
+        (<<E::SqlType as SqlType>::NonNullSqlType as SqlType>::IsNull, TypedFalse): TypesEqual
+}
+
+/// A wrapper around an aggregate expression which makes it only consider
+/// distinct values.
+///
+/// This wrapper shouldn't be used directly, you should instead use the
+/// [`AggregateDistinct::distinct`] function.
+pub struct SqlAggregateDistinct<
+    S: SelectableTables,
+    A: AggregateSqlExpression<S, HasDistinct = TypedFalse>,
+> {
+    aggregate: A,
+    phantom: PhantomData<S>,
+}
+
+impl<S: SelectableTables, A: AggregateSqlExpression<S, HasDistinct = TypedFalse>> SqlExpression<S>
+    for SqlAggregateDistinct<S, A>
 {
-    type RustType = <<E::SqlType as SummableSqlType>::OutputSqlType as SqlType>::RustType;
-    type SqlType = <E::SqlType as SummableSqlType>::OutputSqlType;
+    type RustType = A::RustType;
+    type SqlType = A::SqlType;
 
     const IS_AGGREGATE: bool = true;
 
@@ -143,39 +511,103 @@ where
     where
         's: 'a,
     {
-        write!(f, "SUM(")?;
-        self.expr.write_sql_string(f, parameter_binder)?;
-        write!(f, ")")?;
-        Ok(())
+        self.write_aggregate_sql_string(f, parameter_binder)
     }
 }
 
-pub struct SqlMax<S: SelectableTables, E: SqlExpression<S>>
-where
-    E::SqlType: OrderableSqlType,
+impl<S: SelectableTables, A: AggregateSqlExpression<S, HasDistinct = TypedFalse>>
+    AggregateSqlExpression<S> for SqlAggregateDistinct<S, A>
 {
-    expr: E,
-    phantom: PhantomData<S>,
+    type HasDistinct = TypedTrue;
+    type HasFilter = A::HasFilter;
+    type HasOrderBy = A::HasOrderBy;
+    type IsNullWithoutRows = A::IsNullWithoutRows;
+
+    fn write_function_name(&self, f: &mut String) -> std::fmt::Result {
+        self.aggregate.write_function_name(f)
+    }
+
+    fn write_arguments<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        write!(f, "DISTINCT ")?;
+        self.aggregate.write_arguments(f, parameter_binder)
+    }
+
+    fn write_within_group_clause<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        self.aggregate
+            .write_within_group_clause(f, parameter_binder)
+    }
+
+    fn write_filter_clause<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        self.aggregate.write_filter_clause(f, parameter_binder)
+    }
 }
 
-impl<S: SelectableTables, E: SqlExpression<S>> SqlMax<S, E>
-where
-    E::SqlType: OrderableSqlType,
+/// An aggregate expression which doesn't yet only consider distinct values.
+pub trait AggregateDistinct<S: SelectableTables>:
+    AggregateSqlExpression<S, HasDistinct = TypedFalse>
 {
-    pub fn new(expr: E) -> Self {
-        Self {
-            expr,
+    /// Makes this aggregate only consider distinct values, for example
+    /// `COUNT(DISTINCT x)`.
+    fn distinct(self) -> SqlAggregateDistinct<S, Self> {
+        SqlAggregateDistinct {
+            aggregate: self,
             phantom: PhantomData,
         }
     }
 }
 
-impl<S: SelectableTables, E: SqlExpression<S>> SqlExpression<S> for SqlMax<S, E>
-where
-    E::SqlType: OrderableSqlType,
+impl<S: SelectableTables, A: AggregateSqlExpression<S, HasDistinct = TypedFalse>>
+    AggregateDistinct<S> for A
+{
+}
+
+/// A wrapper around an aggregate expression which makes it consider the values
+/// in the order of some expression.
+///
+/// This wrapper shouldn't be used directly, you should instead use the
+/// [`AggregateOrderBy::order_by_ascending`] and
+/// [`AggregateOrderBy::order_by_descending`] functions.
+pub struct SqlAggregateOrderBy<
+    S: SelectableTables,
+    A: AggregateSqlExpression<S, HasOrderBy = TypedFalse>,
+    B: SqlExpression<S>,
+    O: Ordering,
+> {
+    aggregate: A,
+    order_by: B,
+    phantom: PhantomData<(S, O)>,
+}
+
+impl<
+        S: SelectableTables,
+        A: AggregateSqlExpression<S, HasOrderBy = TypedFalse>,
+        B: SqlExpression<S>,
+        O: Ordering,
+    > SqlExpression<S> for SqlAggregateOrderBy<S, A, B, O>
 {
-    type RustType = E::RustType;
-    type SqlType = E::SqlType;
+    type RustType = A::RustType;
+    type SqlType = A::SqlType;
 
     const IS_AGGREGATE: bool = true;
 
@@ -187,39 +619,135 @@ where
     where
         's: 'a,
     {
-        write!(f, "MAX(")?;
-        self.expr.write_sql_string(f, parameter_binder)?;
-        write!(f, ")")?;
-        Ok(())
+        self.write_aggregate_sql_string(f, parameter_binder)
     }
 }
 
-pub struct SqlMin<S: SelectableTables, E: SqlExpression<S>>
-where
-    E::SqlType: OrderableSqlType,
+impl<
+        S: SelectableTables,
+        A: AggregateSqlExpression<S, HasOrderBy = TypedFalse>,
+        B: SqlExpression<S>,
+        O: Ordering,
+    > AggregateSqlExpression<S> for SqlAggregateOrderBy<S, A, B, O>
 {
-    expr: E,
-    phantom: PhantomData<S>,
+    type HasDistinct = A::HasDistinct;
+    type HasFilter = A::HasFilter;
+    type HasOrderBy = TypedTrue;
+    type IsNullWithoutRows = A::IsNullWithoutRows;
+
+    fn write_function_name(&self, f: &mut String) -> std::fmt::Result {
+        self.aggregate.write_function_name(f)
+    }
+
+    fn write_arguments<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        self.aggregate.write_arguments(f, parameter_binder)?;
+        write!(f, " ORDER BY ")?;
+        self.order_by.write_sql_string(f, parameter_binder)?;
+        write!(f, "{}", O::ORDER_STR)
+    }
+
+    fn write_within_group_clause<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        self.aggregate
+            .write_within_group_clause(f, parameter_binder)
+    }
+
+    fn write_filter_clause<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        self.aggregate.write_filter_clause(f, parameter_binder)
+    }
 }
 
-impl<S: SelectableTables, E: SqlExpression<S>> SqlMin<S, E>
-where
-    E::SqlType: OrderableSqlType,
+/// An aggregate expression which doesn't yet consider its values in a specific
+/// order.
+pub trait AggregateOrderBy<S: SelectableTables>:
+    AggregateSqlExpression<S, HasOrderBy = TypedFalse>
 {
-    pub fn new(expr: E) -> Self {
-        Self {
-            expr,
+    /// Makes this aggregate consider its values in ascending order of the given
+    /// expression, which is useful for aggregates whose result depends on the
+    /// order of the values.
+    fn order_by_ascending<B: SqlExpression<S>>(
+        self,
+        order_by: B,
+    ) -> SqlAggregateOrderBy<S, Self, B, AscendingOrder> {
+        SqlAggregateOrderBy {
+            aggregate: self,
+            order_by,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Makes this aggregate consider its values in descending order of the
+    /// given expression, which is useful for aggregates whose result depends
+    /// on the order of the values.
+    fn order_by_descending<B: SqlExpression<S>>(
+        self,
+        order_by: B,
+    ) -> SqlAggregateOrderBy<S, Self, B, DescendingOrder> {
+        SqlAggregateOrderBy {
+            aggregate: self,
+            order_by,
             phantom: PhantomData,
         }
     }
 }
 
-impl<S: SelectableTables, E: SqlExpression<S>> SqlExpression<S> for SqlMin<S, E>
+impl<S: SelectableTables, A: AggregateSqlExpression<S, HasOrderBy = TypedFalse>> AggregateOrderBy<S>
+    for A
+{
+}
+
+/// A wrapper around an aggregate expression which makes it only consider rows
+/// which match some condition.
+///
+/// This wrapper shouldn't be used directly, you should instead use the
+/// [`AggregateFilter::filter`] function.
+pub struct SqlAggregateFilter<
+    S: SelectableTables,
+    A: AggregateSqlExpression<S, HasFilter = TypedFalse>,
+    C: SqlExpression<S, SqlType = SqlBool>,
+> {
+    aggregate: A,
+    condition: C,
+    phantom: PhantomData<S>,
+}
+
+impl<
+        S: SelectableTables,
+        A: AggregateSqlExpression<S, HasFilter = TypedFalse>,
+        C: SqlExpression<S, SqlType = SqlBool>,
+    > SqlExpression<S> for SqlAggregateFilter<S, A, C>
 where
-    E::SqlType: OrderableSqlType,
+    (
+        <<A::SqlType as SqlType>::NonNullSqlType as SqlType>::IsNull,
+        TypedFalse,
+    ): TypesEqual,
 {
-    type RustType = E::RustType;
-    type SqlType = E::SqlType;
+    // if none of the rows match the filter, most aggregates evaluate to null,
+    // so the result is nullable unless the aggregate is never null.
+    type RustType = <Self::SqlType as SqlType>::RustType;
+    type SqlType = <A::IsNullWithoutRows as TypedBool>::NullableSqlTypeIf<
+        <A::SqlType as SqlType>::NonNullSqlType,
+    >;
 
     const IS_AGGREGATE: bool = true;
 
@@ -231,9 +759,94 @@ where
     where
         's: 'a,
     {
-        write!(f, "MIN(")?;
-        self.expr.write_sql_string(f, parameter_binder)?;
-        write!(f, ")")?;
-        Ok(())
+        self.write_aggregate_sql_string(f, parameter_binder)
+    }
+}
+
+impl<
+        S: SelectableTables,
+        A: AggregateSqlExpression<S, HasFilter = TypedFalse>,
+        C: SqlExpression<S, SqlType = SqlBool>,
+    > AggregateSqlExpression<S> for SqlAggregateFilter<S, A, C>
+where
+    (
+        <<A::SqlType as SqlType>::NonNullSqlType as SqlType>::IsNull,
+        TypedFalse,
+    ): TypesEqual,
+{
+    type HasDistinct = A::HasDistinct;
+    type HasFilter = TypedTrue;
+    type HasOrderBy = A::HasOrderBy;
+    type IsNullWithoutRows = A::IsNullWithoutRows;
+
+    fn write_function_name(&self, f: &mut String) -> std::fmt::Result {
+        self.aggregate.write_function_name(f)
+    }
+
+    fn write_arguments<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        self.aggregate.write_arguments(f, parameter_binder)
+    }
+
+    fn write_within_group_clause<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        self.aggregate
+            .write_within_group_clause(f, parameter_binder)
+    }
+
+    fn write_filter_clause<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        write!(f, " FILTER (WHERE ")?;
+        self.condition.write_sql_string(f, parameter_binder)?;
+        write!(f, ")")
     }
 }
+
+/// An aggregate expression which doesn't yet only consider rows which match
+/// some condition.
+pub trait AggregateFilter<S: SelectableTables>:
+    AggregateSqlExpression<S, HasFilter = TypedFalse>
+{
+    /// Makes this aggregate only consider rows which match the given condition,
+    /// for example `COUNT(*) FILTER (WHERE x > 5)`.
+    ///
+    /// This allows computing multiple conditional aggregates in a single
+    /// grouped query.
+    ///
+    /// Since an aggregate evaluates to null if none of the rows match the
+    /// condition, the filtered aggregate is nullable, except for `COUNT` which
+    /// evaluates to zero in that case.
+    fn filter<C: SqlExpression<S, SqlType = SqlBool>>(
+        self,
+        condition: C,
+    ) -> SqlAggregateFilter<S, Self, C> {
+        SqlAggregateFilter {
+            aggregate: self,
+            condition,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: SelectableTables, A: AggregateSqlExpression<S, HasFilter = TypedFalse>> AggregateFilter<S>
+    for A
+{
+}
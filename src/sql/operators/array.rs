@@ -0,0 +1,130 @@
+use std::{fmt::Write, marker::PhantomData};
+
+use crate::{
+    sql::{
+        ArraySqlType, ParameterBinder, SelectableTables, SelectedValue, SqlBool, SqlComparable,
+        SqlExpression, SqlI32, SqlOption, SqlType,
+    },
+    util::TypesEqual,
+};
+
+define_null_propagating_function! {
+    SqlArrayContains -> condition,
+    "({})@>({})",
+    lhs: Lhs,
+    rhs: Rhs;
+    where Rhs::SqlType: SqlType<NonNullSqlType = <Lhs::SqlType as SqlType>::NonNullSqlType>,
+        <Lhs::SqlType as SqlType>::NonNullSqlType: ArraySqlType
+}
+
+define_null_propagating_function! {
+    SqlArrayContainedBy -> condition,
+    "({})<@({})",
+    lhs: Lhs,
+    rhs: Rhs;
+    where Rhs::SqlType: SqlType<NonNullSqlType = <Lhs::SqlType as SqlType>::NonNullSqlType>,
+        <Lhs::SqlType as SqlType>::NonNullSqlType: ArraySqlType
+}
+
+define_null_propagating_function! {
+    SqlArrayOverlaps -> condition,
+    "({})&&({})",
+    lhs: Lhs,
+    rhs: Rhs;
+    where Rhs::SqlType: SqlType<NonNullSqlType = <Lhs::SqlType as SqlType>::NonNullSqlType>,
+        <Lhs::SqlType as SqlType>::NonNullSqlType: ArraySqlType
+}
+
+define_null_propagating_function! {
+    SqlConditionEqualsAny -> condition,
+    "({}) = ANY({})",
+    value: V,
+    array: A;
+    where <A::SqlType as SqlType>::NonNullSqlType: ArraySqlType,
+        V::SqlType: SqlComparable<
+            <<A::SqlType as SqlType>::NonNullSqlType as ArraySqlType>::ElementSqlType,
+        >
+}
+
+// the length of an empty array is null, so the result is always nullable.
+define_null_propagating_function! {
+    @with_sql_type
+    SqlArrayLength -> SqlOption<SqlI32>,
+    "ARRAY_LENGTH({}, 1)",
+    array: A;
+    where <A::SqlType as SqlType>::NonNullSqlType: ArraySqlType
+}
+
+// appending to a null array results in an array containing only the appended
+// element, so the result is never null.
+define_null_propagating_function! {
+    @with_sql_type
+    SqlArrayAppend -> <A::SqlType as SqlType>::NonNullSqlType,
+    "ARRAY_APPEND({}, {})",
+    array: A,
+    element: E;
+    where <A::SqlType as SqlType>::NonNullSqlType: ArraySqlType,
+        (
+            E::SqlType,
+            <<A::SqlType as SqlType>::NonNullSqlType as ArraySqlType>::ElementSqlType,
+        ): TypesEqual
+}
+
+/// Expands an array to a set of rows, one for each of its elements.
+///
+/// This is only supported in the list of selected values, and not as a source
+/// to select from. Postgres doesn't allow set-returning functions like `UNNEST`
+/// in most other parts of a query, like its `WHERE` clause, so this is not an
+/// sql expression and can't be used in them.
+pub struct SqlUnnest<S: SelectableTables, A: SqlExpression<S>>
+where
+    <A::SqlType as SqlType>::NonNullSqlType: ArraySqlType,
+{
+    array: A,
+    phantom: PhantomData<S>,
+}
+
+impl<S: SelectableTables, A: SqlExpression<S>> SqlUnnest<S, A>
+where
+    <A::SqlType as SqlType>::NonNullSqlType: ArraySqlType,
+{
+    pub fn new(array: A) -> Self {
+        Self {
+            array,
+            phantom: PhantomData,
+        }
+    }
+}
+
+// this negative impl is what allows implementing `SelectedValue` for this type
+// alongside the implementation of it for all sql expressions.
+impl<S: SelectableTables, A: SqlExpression<S>> !SqlExpression<S> for SqlUnnest<S, A> where
+    <A::SqlType as SqlType>::NonNullSqlType: ArraySqlType
+{
+}
+
+impl<S: SelectableTables, A: SqlExpression<S>> SelectedValue<S> for SqlUnnest<S, A>
+where
+    <A::SqlType as SqlType>::NonNullSqlType: ArraySqlType,
+{
+    // unnesting a null array results in no rows at all, so the result is only
+    // nullable if the elements of the array are nullable.
+    type RustType = <
+        <<A::SqlType as SqlType>::NonNullSqlType as ArraySqlType>::ElementSqlType as SqlType
+    >::RustType;
+
+    const IS_AGGREGATE: bool = A::IS_AGGREGATE;
+
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        write!(f, "UNNEST(")?;
+        self.array.write_sql_string(f, parameter_binder)?;
+        write!(f, ")")
+    }
+}
@@ -0,0 +1,24 @@
+use std::marker::PhantomData;
+
+use crate::{
+    sql::{ParameterBinder, SelectableTables, SqlBytea, SqlExpression, SqlI32, SqlText, SqlType},
+    util::TypedBool,
+};
+
+define_null_propagating_function! {SqlByteaLength -> SqlI32, "LENGTH({})", expr: E: SqlBytea}
+
+define_null_propagating_function! {
+    SqlByteaSubstring -> SqlBytea,
+    "SUBSTRING({} FROM {} FOR {})",
+    expr: E: SqlBytea,
+    start: F: SqlI32,
+    length: L: SqlI32
+}
+
+// `MD5` returns the digest as hex encoded text, while the `SHA` functions
+// return the raw bytes of the digest.
+define_null_propagating_function! {SqlMd5 -> SqlText, "MD5({})", expr: E: SqlBytea}
+define_null_propagating_function! {SqlSha224 -> SqlBytea, "SHA224({})", expr: E: SqlBytea}
+define_null_propagating_function! {SqlSha256 -> SqlBytea, "SHA256({})", expr: E: SqlBytea}
+define_null_propagating_function! {SqlSha384 -> SqlBytea, "SHA384({})", expr: E: SqlBytea}
+define_null_propagating_function! {SqlSha512 -> SqlBytea, "SHA512({})", expr: E: SqlBytea}
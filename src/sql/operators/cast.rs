@@ -0,0 +1,54 @@
+use std::{fmt::Write, marker::PhantomData};
+
+use crate::{
+    sql::{ParameterBinder, SelectableTables, SqlCastable, SqlExpression, SqlType},
+    util::{TypedBool, TypedFalse, TypesEqual},
+};
+
+pub struct SqlCast<S: SelectableTables, E: SqlExpression<S>, To: SqlType>
+where
+    <E::SqlType as SqlType>::NonNullSqlType: SqlCastable<To>,
+    (To::IsNull, TypedFalse): TypesEqual,
+{
+    expr: E,
+    phantom: PhantomData<(S, To)>,
+}
+
+impl<S: SelectableTables, E: SqlExpression<S>, To: SqlType> SqlCast<S, E, To>
+where
+    <E::SqlType as SqlType>::NonNullSqlType: SqlCastable<To>,
+    (To::IsNull, TypedFalse): TypesEqual,
+{
+    pub fn new(expr: E) -> Self {
+        Self {
+            expr,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: SelectableTables, E: SqlExpression<S>, To: SqlType> SqlExpression<S> for SqlCast<S, E, To>
+where
+    <E::SqlType as SqlType>::NonNullSqlType: SqlCastable<To>,
+    (To::IsNull, TypedFalse): TypesEqual,
+{
+    // casting a null value results in a null value, so the result is nullable
+    // if the casted expression is nullable.
+    type RustType = <Self::SqlType as SqlType>::RustType;
+    type SqlType = <<E::SqlType as SqlType>::IsNull as TypedBool>::NullableSqlTypeIf<To>;
+
+    const IS_AGGREGATE: bool = E::IS_AGGREGATE;
+
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        write!(f, "CAST(")?;
+        self.expr.write_sql_string(f, parameter_binder)?;
+        write!(f, " AS {})", To::SQL_NAME)
+    }
+}
@@ -0,0 +1,416 @@
+use std::{fmt::Write, marker::PhantomData};
+
+use crate::{
+    sql::{ParameterBinder, SelectableTables, SqlBool, SqlExpression, SqlOption, SqlType},
+    util::{TypedFalse, TypesEqual},
+};
+
+/// Writes the given expression explicitly cast to its own sql type.
+///
+/// If all of the values of a `CASE`, `COALESCE` or `NULLIF` expression are bound
+/// parameters, postgres can't infer their type and assumes that they are text,
+/// so each value must be explicitly cast to its actual type.
+fn write_cast_sql_string<'s, 'a, S: SelectableTables, E: SqlExpression<S>>(
+    expr: &'s E,
+    f: &mut String,
+    parameter_binder: &mut ParameterBinder<'a>,
+) -> std::fmt::Result
+where
+    's: 'a,
+{
+    write!(f, "CAST(")?;
+    expr.write_sql_string(f, parameter_binder)?;
+    write!(
+        f,
+        " AS {})",
+        <<E::SqlType as SqlType>::NonNullSqlType as SqlType>::SQL_NAME
+    )
+}
+
+/// The arguments of an sql `COALESCE` expression.
+pub trait SqlCoalesceArguments<S: SelectableTables> {
+    /// The non-nullable sql type of the arguments.
+    type NonNullSqlType: SqlType;
+
+    /// Does any of the arguments contain an aggregate expression?
+    const IS_AGGREGATE: bool;
+
+    /// Writes the arguments as a comma seperated list.
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a;
+}
+
+/// The first argument of an sql `COALESCE` expression, which marks the start of
+/// the list of arguments of a [`SqlCoalesce`].
+pub struct SqlCoalesceFirstArgument<S: SelectableTables, E: SqlExpression<S>> {
+    expression: E,
+    phantom: PhantomData<S>,
+}
+
+impl<S: SelectableTables, E: SqlExpression<S>> SqlCoalesceArguments<S>
+    for SqlCoalesceFirstArgument<S, E>
+{
+    type NonNullSqlType = <E::SqlType as SqlType>::NonNullSqlType;
+
+    const IS_AGGREGATE: bool = E::IS_AGGREGATE;
+
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        write_cast_sql_string(&self.expression, f, parameter_binder)
+    }
+}
+
+/// An sql `COALESCE` expression, which evaluates to the first of its arguments
+/// which is not null.
+///
+/// More arguments can be added using the [`SqlCoalesce::or_else`] function.
+pub struct SqlCoalesce<S: SelectableTables, P: SqlCoalesceArguments<S>, V: SqlExpression<S>>
+where
+    V::SqlType: SqlType<NonNullSqlType = P::NonNullSqlType>,
+{
+    previous_arguments: P,
+    value: V,
+    phantom: PhantomData<S>,
+}
+
+impl<S: SelectableTables, Lhs: SqlExpression<S>, Rhs: SqlExpression<S>>
+    SqlCoalesce<S, SqlCoalesceFirstArgument<S, Lhs>, Rhs>
+where
+    Lhs::SqlType: SqlType<NonNullSqlType = <Rhs::SqlType as SqlType>::NonNullSqlType>,
+{
+    pub fn new(lhs: Lhs, rhs: Rhs) -> Self {
+        Self {
+            previous_arguments: SqlCoalesceFirstArgument {
+                expression: lhs,
+                phantom: PhantomData,
+            },
+            value: rhs,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: SelectableTables, P: SqlCoalesceArguments<S>, V: SqlExpression<S>> SqlCoalesce<S, P, V>
+where
+    V::SqlType: SqlType<NonNullSqlType = P::NonNullSqlType>,
+{
+    /// Adds an argument which is evaluated to if all of the previous arguments
+    /// are null.
+    ///
+    /// If the given expression is not nullable, the result is not nullable.
+    pub fn or_else<NewV: SqlExpression<S>>(self, value: NewV) -> SqlCoalesce<S, Self, NewV>
+    where
+        NewV::SqlType: SqlType<NonNullSqlType = P::NonNullSqlType>,
+    {
+        SqlCoalesce {
+            previous_arguments: self,
+            value,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: SelectableTables, P: SqlCoalesceArguments<S>, V: SqlExpression<S>> SqlCoalesceArguments<S>
+    for SqlCoalesce<S, P, V>
+where
+    V::SqlType: SqlType<NonNullSqlType = P::NonNullSqlType>,
+{
+    type NonNullSqlType = P::NonNullSqlType;
+
+    const IS_AGGREGATE: bool = P::IS_AGGREGATE || V::IS_AGGREGATE;
+
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        self.previous_arguments
+            .write_sql_string(f, parameter_binder)?;
+        write!(f, ", ")?;
+        write_cast_sql_string(&self.value, f, parameter_binder)
+    }
+}
+
+impl<S: SelectableTables, P: SqlCoalesceArguments<S>, V: SqlExpression<S>> SqlExpression<S>
+    for SqlCoalesce<S, P, V>
+where
+    V::SqlType: SqlType<NonNullSqlType = P::NonNullSqlType>,
+{
+    // the result is only null if the last argument is null, so it has the same
+    // type as the last argument.
+    type RustType = V::RustType;
+    type SqlType = V::SqlType;
+
+    const IS_AGGREGATE: bool = <Self as SqlCoalesceArguments<S>>::IS_AGGREGATE;
+
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        write!(f, "COALESCE(")?;
+        SqlCoalesceArguments::write_sql_string(self, f, parameter_binder)?;
+        write!(f, ")")
+    }
+}
+
+pub struct SqlNullIf<S: SelectableTables, Lhs: SqlExpression<S>, Rhs: SqlExpression<S>>
+where
+    Lhs::SqlType: SqlType<NonNullSqlType = <Rhs::SqlType as SqlType>::NonNullSqlType>,
+    (
+        <<Lhs::SqlType as SqlType>::NonNullSqlType as SqlType>::IsNull,
+        TypedFalse,
+    ): TypesEqual,
+{
+    lhs: Lhs,
+    rhs: Rhs,
+    phantom: PhantomData<S>,
+}
+
+impl<S: SelectableTables, Lhs: SqlExpression<S>, Rhs: SqlExpression<S>> SqlNullIf<S, Lhs, Rhs>
+where
+    Lhs::SqlType: SqlType<NonNullSqlType = <Rhs::SqlType as SqlType>::NonNullSqlType>,
+    (
+        <<Lhs::SqlType as SqlType>::NonNullSqlType as SqlType>::IsNull,
+        TypedFalse,
+    ): TypesEqual,
+{
+    pub fn new(lhs: Lhs, rhs: Rhs) -> Self {
+        Self {
+            lhs,
+            rhs,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: SelectableTables, Lhs: SqlExpression<S>, Rhs: SqlExpression<S>> SqlExpression<S>
+    for SqlNullIf<S, Lhs, Rhs>
+where
+    Lhs::SqlType: SqlType<NonNullSqlType = <Rhs::SqlType as SqlType>::NonNullSqlType>,
+    (
+        <<Lhs::SqlType as SqlType>::NonNullSqlType as SqlType>::IsNull,
+        TypedFalse,
+    ): TypesEqual,
+{
+    type RustType = <Self::SqlType as SqlType>::RustType;
+    type SqlType = SqlOption<<Lhs::SqlType as SqlType>::NonNullSqlType>;
+
+    const IS_AGGREGATE: bool = Lhs::IS_AGGREGATE || Rhs::IS_AGGREGATE;
+
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        write!(f, "NULLIF(")?;
+        write_cast_sql_string(&self.lhs, f, parameter_binder)?;
+        write!(f, ", ")?;
+        write_cast_sql_string(&self.rhs, f, parameter_binder)?;
+        write!(f, ")")?;
+        Ok(())
+    }
+}
+
+/// The `WHEN` branches of an sql `CASE` expression.
+pub trait SqlCaseWhenBranches<S: SelectableTables> {
+    /// The sql type of the values of the branches.
+    type SqlType: SqlType;
+
+    /// Does any of the branches contain an aggregate expression?
+    const IS_AGGREGATE: bool;
+
+    /// Writes the branches as a space seperated list of `WHEN ... THEN ...`
+    /// clauses.
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a;
+}
+
+/// An empty list of `WHEN` branches, which marks the start of the list of
+/// branches of a [`SqlCaseWhen`].
+pub struct SqlCaseWhenNoBranches<T: SqlType>(PhantomData<T>);
+
+impl<S: SelectableTables, T: SqlType> SqlCaseWhenBranches<S> for SqlCaseWhenNoBranches<T> {
+    type SqlType = T;
+
+    const IS_AGGREGATE: bool = false;
+
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        _f: &mut String,
+        _parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        Ok(())
+    }
+}
+
+/// A list of `WHEN` branches of an sql `CASE` expression, whose values all have
+/// the same sql type.
+///
+/// To use it as an expression, a value for the case where none of the
+/// conditions are true must be provided using the [`SqlCaseWhen::otherwise`]
+/// function.
+pub struct SqlCaseWhen<
+    S: SelectableTables,
+    P: SqlCaseWhenBranches<S, SqlType = V::SqlType>,
+    C: SqlExpression<S, SqlType = SqlBool>,
+    V: SqlExpression<S>,
+> {
+    previous_branches: P,
+    condition: C,
+    value: V,
+    phantom: PhantomData<S>,
+}
+
+impl<S: SelectableTables, C: SqlExpression<S, SqlType = SqlBool>, V: SqlExpression<S>>
+    SqlCaseWhen<S, SqlCaseWhenNoBranches<V::SqlType>, C, V>
+{
+    pub fn new(condition: C, value: V) -> Self {
+        Self {
+            previous_branches: SqlCaseWhenNoBranches(PhantomData),
+            condition,
+            value,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<
+        S: SelectableTables,
+        P: SqlCaseWhenBranches<S, SqlType = V::SqlType>,
+        C: SqlExpression<S, SqlType = SqlBool>,
+        V: SqlExpression<S>,
+    > SqlCaseWhen<S, P, C, V>
+{
+    /// Adds a branch which evaluates to the given value if the given condition
+    /// is true and the conditions of all previous branches are false.
+    pub fn when<
+        NewC: SqlExpression<S, SqlType = SqlBool>,
+        NewV: SqlExpression<S, SqlType = V::SqlType>,
+    >(
+        self,
+        condition: NewC,
+        value: NewV,
+    ) -> SqlCaseWhen<S, Self, NewC, NewV> {
+        SqlCaseWhen {
+            previous_branches: self,
+            condition,
+            value,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns an expression which evaluates to the value of the first branch
+    /// whose condition is true, or to the given value if none of the conditions
+    /// are true.
+    pub fn otherwise<O: SqlExpression<S, SqlType = V::SqlType>>(
+        self,
+        value: O,
+    ) -> SqlCase<S, Self, O> {
+        SqlCase::new(self, value)
+    }
+}
+
+impl<
+        S: SelectableTables,
+        P: SqlCaseWhenBranches<S, SqlType = V::SqlType>,
+        C: SqlExpression<S, SqlType = SqlBool>,
+        V: SqlExpression<S>,
+    > SqlCaseWhenBranches<S> for SqlCaseWhen<S, P, C, V>
+{
+    type SqlType = V::SqlType;
+
+    const IS_AGGREGATE: bool = P::IS_AGGREGATE || C::IS_AGGREGATE || V::IS_AGGREGATE;
+
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        self.previous_branches
+            .write_sql_string(f, parameter_binder)?;
+        write!(f, " WHEN ")?;
+        self.condition.write_sql_string(f, parameter_binder)?;
+        write!(f, " THEN ")?;
+        write_cast_sql_string(&self.value, f, parameter_binder)?;
+        Ok(())
+    }
+}
+
+pub struct SqlCase<
+    S: SelectableTables,
+    B: SqlCaseWhenBranches<S>,
+    O: SqlExpression<S, SqlType = B::SqlType>,
+> {
+    branches: B,
+    otherwise: O,
+    phantom: PhantomData<S>,
+}
+
+impl<S: SelectableTables, B: SqlCaseWhenBranches<S>, O: SqlExpression<S, SqlType = B::SqlType>>
+    SqlCase<S, B, O>
+{
+    pub fn new(branches: B, otherwise: O) -> Self {
+        Self {
+            branches,
+            otherwise,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: SelectableTables, B: SqlCaseWhenBranches<S>, O: SqlExpression<S, SqlType = B::SqlType>>
+    SqlExpression<S> for SqlCase<S, B, O>
+{
+    type RustType = O::RustType;
+    type SqlType = O::SqlType;
+
+    const IS_AGGREGATE: bool = B::IS_AGGREGATE || O::IS_AGGREGATE;
+
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        write!(f, "CASE")?;
+        self.branches.write_sql_string(f, parameter_binder)?;
+        write!(f, " ELSE ")?;
+        write_cast_sql_string(&self.otherwise, f, parameter_binder)?;
+        write!(f, " END")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,231 @@
+use std::{fmt::Write, marker::PhantomData};
+
+use crate::{
+    sql::{
+        JsonSqlType, ParameterBinder, SelectableTables, SqlArray, SqlBool, SqlExpression, SqlI32,
+        SqlJsonb, SqlOption, SqlText, SqlType,
+    },
+    util::TypedBool,
+};
+
+// accessing a field or an element of a json value results in null if it
+// doesn't exist, so the result is always nullable.
+define_null_propagating_function! {
+    @with_sql_type
+    SqlJsonGet -> SqlOption<<J::SqlType as SqlType>::NonNullSqlType>,
+    "({})->({})",
+    json: J,
+    key: K: SqlText;
+    where <J::SqlType as SqlType>::NonNullSqlType: JsonSqlType
+}
+
+define_null_propagating_function! {
+    @with_sql_type
+    SqlJsonGetText -> SqlOption<SqlText>,
+    "({})->>({})",
+    json: J,
+    key: K: SqlText;
+    where <J::SqlType as SqlType>::NonNullSqlType: JsonSqlType
+}
+
+define_null_propagating_function! {
+    @with_sql_type
+    SqlJsonGetIndex -> SqlOption<<J::SqlType as SqlType>::NonNullSqlType>,
+    "({})->(CAST({} AS integer))",
+    json: J,
+    index: I: SqlI32;
+    where <J::SqlType as SqlType>::NonNullSqlType: JsonSqlType
+}
+
+define_null_propagating_function! {
+    @with_sql_type
+    SqlJsonGetIndexText -> SqlOption<SqlText>,
+    "({})->>(CAST({} AS integer))",
+    json: J,
+    index: I: SqlI32;
+    where <J::SqlType as SqlType>::NonNullSqlType: JsonSqlType
+}
+
+define_null_propagating_function! {
+    @with_sql_type
+    SqlJsonGetPath -> SqlOption<<J::SqlType as SqlType>::NonNullSqlType>,
+    "({})#>({})",
+    json: J,
+    path: P: SqlArray<SqlText>;
+    where <J::SqlType as SqlType>::NonNullSqlType: JsonSqlType
+}
+
+define_null_propagating_function! {
+    SqlJsonbContains -> condition,
+    "({})@>({})",
+    lhs: Lhs: SqlJsonb,
+    rhs: Rhs: SqlJsonb
+}
+
+define_null_propagating_function! {
+    SqlJsonbHasKey -> condition,
+    "({})?({})",
+    json: J: SqlJsonb,
+    key: K: SqlText
+}
+
+define_null_propagating_function! {
+    SqlJsonbSet -> SqlJsonb,
+    "JSONB_SET({}, {}, {})",
+    json: J: SqlJsonb,
+    path: P: SqlArray<SqlText>,
+    value: V: SqlJsonb
+}
+
+/// The key value pairs of an sql `JSONB_BUILD_OBJECT` expression.
+pub trait SqlJsonbObjectFields<S: SelectableTables> {
+    /// Are there no fields in this list?
+    const IS_EMPTY: bool;
+
+    /// Does any of the fields contain an aggregate expression?
+    const IS_AGGREGATE: bool;
+
+    /// Writes the fields as a comma seperated list of keys and values.
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a;
+}
+
+/// An empty list of fields, which marks the start of the list of fields of a
+/// [`SqlJsonbBuildObject`].
+pub struct SqlJsonbObjectNoFields;
+
+impl<S: SelectableTables> SqlJsonbObjectFields<S> for SqlJsonbObjectNoFields {
+    const IS_AGGREGATE: bool = false;
+    const IS_EMPTY: bool = true;
+
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        _f: &mut String,
+        _parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        Ok(())
+    }
+}
+
+/// An sql `JSONB_BUILD_OBJECT` expression, which builds a `jsonb` object out
+/// of a list of keys and values.
+///
+/// More fields can be added to the object using the
+/// [`SqlJsonbBuildObject::field`] function.
+pub struct SqlJsonbBuildObject<
+    S: SelectableTables,
+    P: SqlJsonbObjectFields<S>,
+    K: SqlExpression<S, SqlType = SqlText>,
+    V: SqlExpression<S>,
+> {
+    previous_fields: P,
+    key: K,
+    value: V,
+    phantom: PhantomData<S>,
+}
+
+impl<S: SelectableTables, K: SqlExpression<S, SqlType = SqlText>, V: SqlExpression<S>>
+    SqlJsonbBuildObject<S, SqlJsonbObjectNoFields, K, V>
+{
+    pub fn new(key: K, value: V) -> Self {
+        Self {
+            previous_fields: SqlJsonbObjectNoFields,
+            key,
+            value,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<
+        S: SelectableTables,
+        P: SqlJsonbObjectFields<S>,
+        K: SqlExpression<S, SqlType = SqlText>,
+        V: SqlExpression<S>,
+    > SqlJsonbBuildObject<S, P, K, V>
+{
+    /// Adds a field with the given key and value to the object.
+    pub fn field<NewK: SqlExpression<S, SqlType = SqlText>, NewV: SqlExpression<S>>(
+        self,
+        key: NewK,
+        value: NewV,
+    ) -> SqlJsonbBuildObject<S, Self, NewK, NewV> {
+        SqlJsonbBuildObject {
+            previous_fields: self,
+            key,
+            value,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<
+        S: SelectableTables,
+        P: SqlJsonbObjectFields<S>,
+        K: SqlExpression<S, SqlType = SqlText>,
+        V: SqlExpression<S>,
+    > SqlJsonbObjectFields<S> for SqlJsonbBuildObject<S, P, K, V>
+{
+    const IS_AGGREGATE: bool = P::IS_AGGREGATE || K::IS_AGGREGATE || V::IS_AGGREGATE;
+    const IS_EMPTY: bool = false;
+
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        self.previous_fields.write_sql_string(f, parameter_binder)?;
+        if !P::IS_EMPTY {
+            write!(f, ", ")?;
+        }
+
+        // the arguments of `JSONB_BUILD_OBJECT` can be of any type, so bound
+        // parameters must be explicitly cast for postgres to know their type.
+        write!(f, "CAST(")?;
+        self.key.write_sql_string(f, parameter_binder)?;
+        write!(f, " AS text), CAST(")?;
+        self.value.write_sql_string(f, parameter_binder)?;
+        write!(
+            f,
+            " AS {})",
+            <<V::SqlType as SqlType>::NonNullSqlType as SqlType>::SQL_NAME
+        )
+    }
+}
+
+impl<
+        S: SelectableTables,
+        P: SqlJsonbObjectFields<S>,
+        K: SqlExpression<S, SqlType = SqlText>,
+        V: SqlExpression<S>,
+    > SqlExpression<S> for SqlJsonbBuildObject<S, P, K, V>
+{
+    type RustType = <Self::SqlType as SqlType>::RustType;
+    type SqlType = SqlJsonb;
+
+    const IS_AGGREGATE: bool = <Self as SqlJsonbObjectFields<S>>::IS_AGGREGATE;
+
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        write!(f, "JSONB_BUILD_OBJECT(")?;
+        SqlJsonbObjectFields::write_sql_string(self, f, parameter_binder)?;
+        write!(f, ")")
+    }
+}
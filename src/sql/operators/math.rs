@@ -0,0 +1,84 @@
+use std::marker::PhantomData;
+
+use crate::{
+    sql::{
+        MathFunctionSqlType, OrderableSqlType, ParameterBinder, SelectableTables, SqlComparable,
+        SqlExpression, SqlI32, SqlNumeric, SqlType,
+    },
+    util::TypedBool,
+};
+
+// the absolute value has the same type as the value, so it is already nullable
+// if the value is nullable.
+define_null_propagating_function! {
+    @with_sql_type
+    SqlAbs -> E::SqlType,
+    "ABS({})",
+    expr: E;
+    where <E::SqlType as SqlType>::NonNullSqlType: MathFunctionSqlType
+}
+
+define_null_propagating_function! {
+    SqlCeil -> <<E::SqlType as SqlType>::NonNullSqlType as MathFunctionSqlType>::OutputSqlType,
+    "CEIL({})",
+    expr: E;
+    where <E::SqlType as SqlType>::NonNullSqlType: MathFunctionSqlType
+}
+
+define_null_propagating_function! {
+    SqlFloor -> <<E::SqlType as SqlType>::NonNullSqlType as MathFunctionSqlType>::OutputSqlType,
+    "FLOOR({})",
+    expr: E;
+    where <E::SqlType as SqlType>::NonNullSqlType: MathFunctionSqlType
+}
+
+define_null_propagating_function! {
+    SqlSqrt -> <<E::SqlType as SqlType>::NonNullSqlType as MathFunctionSqlType>::OutputSqlType,
+    "SQRT({})",
+    expr: E;
+    where <E::SqlType as SqlType>::NonNullSqlType: MathFunctionSqlType
+}
+
+define_null_propagating_function! {
+    SqlPower -> <<B::SqlType as SqlType>::NonNullSqlType as MathFunctionSqlType>::OutputSqlType,
+    "POWER({}, {})",
+    base: B,
+    exponent: X;
+    where
+        <B::SqlType as SqlType>::NonNullSqlType: MathFunctionSqlType,
+        <X::SqlType as SqlType>::NonNullSqlType: MathFunctionSqlType<
+            OutputSqlType = <<B::SqlType as SqlType>::NonNullSqlType as MathFunctionSqlType>::OutputSqlType
+        >
+}
+
+define_null_propagating_function! {
+    SqlRound -> SqlNumeric,
+    "ROUND({}, {})",
+    expr: E: SqlNumeric,
+    digits: D: SqlI32
+}
+
+// `GREATEST` and `LEAST` ignore null arguments, so their result is only null if
+// all of their arguments are null. The result thus can't be null if the first
+// argument can't be null, so it is typed as the type of the first argument.
+define_null_propagating_function! {
+    @with_sql_type
+    SqlGreatest -> Lhs::SqlType,
+    "GREATEST({}, {})",
+    lhs: Lhs,
+    rhs: Rhs;
+    where
+        Lhs::SqlType: OrderableSqlType,
+        Lhs::SqlType: SqlComparable<Rhs::SqlType>
+}
+
+define_null_propagating_function! {
+    @with_sql_type
+    SqlLeast -> Lhs::SqlType,
+    "LEAST({}, {})",
+    lhs: Lhs,
+    rhs: Rhs;
+    where
+        Lhs::SqlType: OrderableSqlType,
+        Lhs::SqlType: SqlComparable<Rhs::SqlType>
+}
@@ -1,7 +1,137 @@
+/// Expands to a typed bool which is true if the sql type of any of the given
+/// expression types is nullable.
+macro_rules! any_sql_type_is_null {
+    ($generic: ident) => {
+        <$generic::SqlType as SqlType>::IsNull
+    };
+    ($generic: ident, $($rest: ident),+) => {
+        <<$generic::SqlType as SqlType>::IsNull as TypedBool>::Or<any_sql_type_is_null!($($rest),+)>
+    };
+}
+
+/// Defines an sql function expression struct, whose result is null if any of
+/// its arguments is null, like most of the builtin sql functions.
+///
+/// Each argument may specify the non-nullable sql type that it must have, and
+/// additional bounds on the arguments can be specified in a `where` clause.
+///
+/// Functions which return a condition are defined with `condition` as their
+/// output type, and always evaluate to a non-nullable boolean, just like other
+/// conditions. Functions whose output type is already nullable if any of their
+/// arguments is nullable are defined with `@with_sql_type`.
+///
+/// The sql string of the function is written by replacing each `{}` in the
+/// given sql format string with the sql string of the matching argument.
+macro_rules! define_null_propagating_function {
+    {
+        $type_name: ident -> condition,
+        $sql_format: literal,
+        $($field: ident: $generic: ident $(: $arg_sql_type: ty)?),+
+        $(; where $($bounded_type: ty: $bound: path),+)?
+    } => {
+        define_null_propagating_function! {
+            @with_sql_type
+            $type_name -> SqlBool,
+            $sql_format,
+            $($field: $generic $(: $arg_sql_type)?),+
+            $(; where $($bounded_type: $bound),+)?
+        }
+    };
+    {
+        $type_name: ident -> $output_sql_type: ty,
+        $sql_format: literal,
+        $($field: ident: $generic: ident $(: $arg_sql_type: ty)?),+
+        $(; where $($bounded_type: ty: $bound: path),+)?
+    } => {
+        define_null_propagating_function! {
+            @with_sql_type
+            $type_name
+                -> <any_sql_type_is_null!($($generic),+) as TypedBool>::NullableSqlTypeIf<$output_sql_type>,
+            $sql_format,
+            $($field: $generic $(: $arg_sql_type)?),+
+            $(; where $($bounded_type: $bound),+)?
+        }
+    };
+    {
+        @with_sql_type
+        $type_name: ident -> $sql_type: ty,
+        $sql_format: literal,
+        $($field: ident: $generic: ident $(: $arg_sql_type: ty)?),+
+        $(; where $($bounded_type: ty: $bound: path),+)?
+    } => {
+        pub struct $type_name<S: SelectableTables, $($generic: SqlExpression<S>),+>
+        where
+            $($($generic::SqlType: SqlType<NonNullSqlType = $arg_sql_type>,)?)+
+            $($($bounded_type: $bound,)+)?
+        {
+            $($field: $generic,)+
+            phantom: PhantomData<S>,
+        }
+
+        impl<S: SelectableTables, $($generic: SqlExpression<S>),+> $type_name<S, $($generic),+>
+        where
+            $($($generic::SqlType: SqlType<NonNullSqlType = $arg_sql_type>,)?)+
+            $($($bounded_type: $bound,)+)?
+        {
+            pub fn new($($field: $generic),+) -> Self {
+                Self {
+                    $($field,)+
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<S: SelectableTables, $($generic: SqlExpression<S>),+> SqlExpression<S>
+            for $type_name<S, $($generic),+>
+        where
+            $($($generic::SqlType: SqlType<NonNullSqlType = $arg_sql_type>,)?)+
+            $($($bounded_type: $bound,)+)?
+        {
+            type SqlType = $sql_type;
+            type RustType = <Self::SqlType as SqlType>::RustType;
+
+            const IS_AGGREGATE:bool = $($generic::IS_AGGREGATE)||+;
+
+            fn write_sql_string<'s, 'a>(
+                &'s self,
+                f: &mut String,
+                parameter_binder: &mut ParameterBinder<'a>,
+            ) -> std::fmt::Result
+            where
+                's: 'a,
+            {
+                let mut sql_format_parts = $sql_format.split("{}");
+                $(
+                    f.push_str(sql_format_parts.next().unwrap_or_default());
+                    self.$field.write_sql_string(f, parameter_binder)?;
+                )+
+                f.push_str(sql_format_parts.next().unwrap_or_default());
+                Ok(())
+            }
+        }
+    };
+}
+
 mod aggregate;
+mod array;
 mod boolean;
+mod bytea;
+mod cast;
+mod conditional;
+mod json;
+mod math;
 mod regular;
+mod temporal;
+mod text;
 
 pub use aggregate::*;
+pub use array::*;
 pub use boolean::*;
+pub use bytea::*;
+pub use cast::*;
+pub use conditional::*;
+pub use json::*;
+pub use math::*;
 pub use regular::*;
+pub use temporal::*;
+pub use text::*;
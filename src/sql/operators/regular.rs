@@ -1,7 +1,11 @@
 use std::{fmt::Write, marker::PhantomData};
 
-use crate::sql::{
-    ParameterBinder, SelectableTables, SqlAdd, SqlDivide, SqlExpression, SqlMultiply, SqlSubtract,
+use crate::{
+    sql::{
+        DifferenceableSqlType, ParameterBinder, SelectableTables, SqlAdd, SqlBitwise, SqlDivide,
+        SqlExpression, SqlMod, SqlMultiply, SqlShift, SqlSubtract, SqlType,
+    },
+    util::TypedBool,
 };
 
 macro_rules! define_operator{
@@ -49,7 +53,21 @@ macro_rules! define_operator{
             {
                 self.lhs.write_parenthesized_sql_string(f, parameter_binder)?;
                 write!(f, stringify!($operator))?;
-                self.rhs.write_parenthesized_sql_string(f, parameter_binder)?;
+
+                // postgres assumes that a bound parameter is of the same type
+                // as the other operand, so if the operands are of different
+                // types, the rhs must be explicitly cast to its actual type.
+                let lhs_sql_name =
+                    <<Lhs::SqlType as SqlType>::NonNullSqlType as SqlType>::SQL_NAME;
+                let rhs_sql_name =
+                    <<Rhs::SqlType as SqlType>::NonNullSqlType as SqlType>::SQL_NAME;
+                if lhs_sql_name == rhs_sql_name {
+                    self.rhs.write_parenthesized_sql_string(f, parameter_binder)?;
+                } else {
+                    write!(f, "(CAST(")?;
+                    self.rhs.write_sql_string(f, parameter_binder)?;
+                    write!(f, " AS {}))", rhs_sql_name)?;
+                }
                 Ok(())
             }
         }
@@ -61,3 +79,64 @@ define_operator! {SqlAddition, SqlAdd, +}
 define_operator! {SqlSubtraction, SqlSubtract, -}
 define_operator! {SqlMultiplication, SqlMultiply, *}
 define_operator! {SqlDivision, SqlDivide, /}
+define_operator! {SqlModulo, SqlMod, %}
+define_operator! {SqlBitwiseAnd, SqlBitwise, &}
+define_operator! {SqlBitwiseOr, SqlBitwise, |}
+define_operator! {SqlBitwiseXor, SqlBitwise, #}
+define_operator! {SqlShiftLeft, SqlShift, <<}
+define_operator! {SqlShiftRight, SqlShift, >>}
+
+define_null_propagating_function! {
+    SqlDifference
+        -> <<Lhs::SqlType as SqlType>::NonNullSqlType as DifferenceableSqlType<
+            <Rhs::SqlType as SqlType>::NonNullSqlType,
+        >>::OutputSqlType,
+    "({})-({})",
+    lhs: Lhs,
+    rhs: Rhs;
+    where <Lhs::SqlType as SqlType>::NonNullSqlType:
+        DifferenceableSqlType<<Rhs::SqlType as SqlType>::NonNullSqlType>
+}
+
+pub struct SqlBitwiseNot<S: SelectableTables, E: SqlExpression<S>>
+where
+    E::SqlType: SqlBitwise<E::SqlType>,
+{
+    expr: E,
+    phantom: PhantomData<S>,
+}
+
+impl<S: SelectableTables, E: SqlExpression<S>> SqlBitwiseNot<S, E>
+where
+    E::SqlType: SqlBitwise<E::SqlType>,
+{
+    pub fn new(expr: E) -> Self {
+        Self {
+            expr,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: SelectableTables, E: SqlExpression<S>> SqlExpression<S> for SqlBitwiseNot<S, E>
+where
+    E::SqlType: SqlBitwise<E::SqlType>,
+{
+    type RustType = E::RustType;
+    type SqlType = E::SqlType;
+
+    const IS_AGGREGATE: bool = E::IS_AGGREGATE;
+
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        write!(f, "~")?;
+        self.expr
+            .write_parenthesized_sql_string(f, parameter_binder)
+    }
+}
@@ -0,0 +1,147 @@
+use std::{fmt::Write, marker::PhantomData};
+
+use crate::{
+    sql::{
+        ParameterBinder, SelectableTables, SqlExpression, SqlF64, SqlInterval, SqlType,
+        TemporalSqlType, TimestampSqlType,
+    },
+    util::TypedBool,
+};
+
+/// A field of an sql date and time value, like its year or its hour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateTimeField {
+    Microseconds,
+    Milliseconds,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+    Decade,
+    Century,
+    Millennium,
+
+    /// The amount of seconds since 1970-01-01 00:00:00 UTC for timestamps, or
+    /// the total amount of seconds for intervals.
+    ///
+    /// This field can only be extracted, and can't be truncated to.
+    Epoch,
+
+    /// The day of the week, from 0 for sunday to 6 for saturday.
+    ///
+    /// This field can only be extracted, and can't be truncated to.
+    DayOfWeek,
+
+    /// The day of the year, from 1 to 366.
+    ///
+    /// This field can only be extracted, and can't be truncated to.
+    DayOfYear,
+}
+impl DateTimeField {
+    /// Returns the sql name of this field.
+    pub fn sql_name(&self) -> &'static str {
+        match self {
+            DateTimeField::Microseconds => "microseconds",
+            DateTimeField::Milliseconds => "milliseconds",
+            DateTimeField::Second => "second",
+            DateTimeField::Minute => "minute",
+            DateTimeField::Hour => "hour",
+            DateTimeField::Day => "day",
+            DateTimeField::Week => "week",
+            DateTimeField::Month => "month",
+            DateTimeField::Quarter => "quarter",
+            DateTimeField::Year => "year",
+            DateTimeField::Decade => "decade",
+            DateTimeField::Century => "century",
+            DateTimeField::Millennium => "millennium",
+            DateTimeField::Epoch => "epoch",
+            DateTimeField::DayOfWeek => "dow",
+            DateTimeField::DayOfYear => "doy",
+        }
+    }
+}
+
+/// Defines an sql function expression struct which operates on some field of
+/// a date and time expression.
+macro_rules! define_date_time_field_function {
+    {
+        $type_name: ident -> $sql_type: ty,
+        $function_name: literal;
+        where <E::SqlType as SqlType>::NonNullSqlType: $bound: path
+    } => {
+        pub struct $type_name<S: SelectableTables, E: SqlExpression<S>>
+        where
+            <E::SqlType as SqlType>::NonNullSqlType: $bound,
+        {
+            field: DateTimeField,
+            expr: E,
+            phantom: PhantomData<S>,
+        }
+
+        impl<S: SelectableTables, E: SqlExpression<S>> $type_name<S, E>
+        where
+            <E::SqlType as SqlType>::NonNullSqlType: $bound,
+        {
+            pub fn new(field: DateTimeField, expr: E) -> Self {
+                Self {
+                    field,
+                    expr,
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<S: SelectableTables, E: SqlExpression<S>> SqlExpression<S> for $type_name<S, E>
+        where
+            <E::SqlType as SqlType>::NonNullSqlType: $bound,
+        {
+            type SqlType = $sql_type;
+            type RustType = <Self::SqlType as SqlType>::RustType;
+
+            const IS_AGGREGATE: bool = E::IS_AGGREGATE;
+
+            fn write_sql_string<'s, 'a>(
+                &'s self,
+                f: &mut String,
+                parameter_binder: &mut ParameterBinder<'a>,
+            ) -> std::fmt::Result
+            where
+                's: 'a,
+            {
+                write!(f, concat!($function_name, "('{}', "), self.field.sql_name())?;
+                self.expr.write_sql_string(f, parameter_binder)?;
+                write!(f, ")")
+            }
+        }
+    };
+}
+
+// truncating a timestamp results in a timestamp of the same type, so it is
+// already nullable if the timestamp is nullable.
+define_date_time_field_function! {
+    SqlDateTrunc -> E::SqlType,
+    "DATE_TRUNC";
+    where <E::SqlType as SqlType>::NonNullSqlType: TimestampSqlType
+}
+
+// `DATE_PART` is used instead of `EXTRACT` because it returns a double
+// precision number on all versions of postgres, while `EXTRACT` returns a
+// numeric since postgres 14.
+define_date_time_field_function! {
+    SqlExtract -> <<E::SqlType as SqlType>::IsNull as TypedBool>::NullableSqlTypeIf<SqlF64>,
+    "DATE_PART";
+    where <E::SqlType as SqlType>::NonNullSqlType: TemporalSqlType
+}
+
+define_null_propagating_function! {
+    SqlAge -> SqlInterval,
+    "AGE({}, {})",
+    lhs: Lhs,
+    rhs: Rhs;
+    where Lhs::SqlType: SqlType<NonNullSqlType = <Rhs::SqlType as SqlType>::NonNullSqlType>,
+        <Lhs::SqlType as SqlType>::NonNullSqlType: TimestampSqlType
+}
@@ -0,0 +1,51 @@
+use std::marker::PhantomData;
+
+use crate::{
+    sql::{ParameterBinder, SelectableTables, SqlBool, SqlExpression, SqlI32, SqlText, SqlType},
+    util::TypedBool,
+};
+
+define_null_propagating_function! {SqlLower -> SqlText, "LOWER({})", expr: E: SqlText}
+define_null_propagating_function! {SqlUpper -> SqlText, "UPPER({})", expr: E: SqlText}
+define_null_propagating_function! {SqlLength -> SqlI32, "LENGTH({})", expr: E: SqlText}
+define_null_propagating_function! {SqlTrim -> SqlText, "TRIM({})", expr: E: SqlText}
+
+define_null_propagating_function! {
+    SqlSubstring -> SqlText,
+    "SUBSTRING({} FROM {} FOR {})",
+    expr: E: SqlText,
+    start: F: SqlI32,
+    length: L: SqlI32
+}
+
+define_null_propagating_function! {
+    SqlReplace -> SqlText,
+    "REPLACE({}, {}, {})",
+    expr: E: SqlText,
+    from: F: SqlText,
+    to: T: SqlText
+}
+
+define_null_propagating_function! {
+    SqlConcat -> SqlText,
+    "(({}) || ({}))",
+    lhs: Lhs: SqlText,
+    rhs: Rhs: SqlText
+}
+
+define_null_propagating_function! {SqlLeft -> SqlText, "LEFT({}, {})", expr: E: SqlText, n: N: SqlI32}
+define_null_propagating_function! {SqlRight -> SqlText, "RIGHT({}, {})", expr: E: SqlText, n: N: SqlI32}
+
+define_null_propagating_function! {
+    SqlStartsWith -> condition,
+    "STARTS_WITH({}, {})",
+    expr: E: SqlText,
+    prefix: P: SqlText
+}
+
+define_null_propagating_function! {
+    SqlPosition -> SqlI32,
+    "POSITION({} IN {})",
+    substring: Sub: SqlText,
+    expr: E: SqlText
+}
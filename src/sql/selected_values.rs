@@ -1,6 +1,6 @@
 use super::{Column, FieldNameCharsConsListItem, FieldsConsListCons, SelectableTablesContains};
 use crate::{
-    sql::{FieldsConsListItem, ParameterBinder, SelectableTables},
+    sql::{FieldsConsListItem, IntoSqlType, ParameterBinder, SelectableTables, SqlExpression},
     util::TypedConsListNil,
 };
 
@@ -21,6 +21,50 @@ pub trait SelectedValues<S: SelectableTables> {
         's: 'a;
 }
 
+/// A single value which can be selected in an sql query, for example using the
+/// `select_values!` macro.
+///
+/// Every sql expression can be selected, but some values, like the results of
+/// set-returning functions, are only supported in the list of selected values
+/// and are not sql expressions, so that they can't be used in other parts of
+/// the query where postgres doesn't allow them, for example in its `WHERE`
+/// clause.
+pub trait SelectedValue<S: SelectableTables> {
+    /// The rust type of this selected value.
+    type RustType: IntoSqlType;
+
+    /// Is this selected value an aggregate expression?
+    const IS_AGGREGATE: bool;
+
+    /// Writes the selected value as an sql string which can be selected by the
+    /// database.
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a;
+}
+
+// An sql expression is considered a `SelectedValue`.
+impl<S: SelectableTables, E: SqlExpression<S>> SelectedValue<S> for E {
+    type RustType = E::RustType;
+
+    const IS_AGGREGATE: bool = E::IS_AGGREGATE;
+
+    fn write_sql_string<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        SqlExpression::write_sql_string(self, f, parameter_binder)
+    }
+}
+
 /// A marker trait which indicates that the list of selected values contains a
 /// field with the given name.
 pub trait SelectedValuesContainsFieldWithName<N: FieldNameCharsConsListItem> {}
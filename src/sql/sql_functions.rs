@@ -1,10 +1,21 @@
 use super::{
-    AverageableSqlType, OrderableSqlType, SelectableTables, SqlAverage, SqlBool, SqlCount,
-    SqlCountRows, SqlExpression, SqlMax, SqlMin, SqlNot, SqlSum, SummableSqlType,
+    ArrayElementSqlType, ArraySqlType, AverageableSqlType, ContinuousPercentileSqlType,
+    DateTimeField, MathFunctionSqlType, OrderableSqlType, SelectableTables, SqlAbs, SqlAge,
+    SqlArray, SqlArrayAgg, SqlArrayAppend, SqlArrayLength, SqlAverage, SqlBool, SqlBoolAnd,
+    SqlBoolOr, SqlBytea, SqlCaseWhen, SqlCaseWhenNoBranches, SqlCeil, SqlCoalesce,
+    SqlCoalesceFirstArgument, SqlComparable, SqlConcat, SqlCount, SqlCountRows, SqlDateTrunc,
+    SqlExpression, SqlExtract, SqlF64, SqlFloor, SqlGreatest, SqlI32, SqlJsonb,
+    SqlJsonbBuildObject, SqlJsonbObjectNoFields, SqlJsonbSet, SqlLeast, SqlLeft, SqlLength,
+    SqlLower, SqlMax, SqlMd5, SqlMin, SqlNot, SqlNullIf, SqlNumeric, SqlPercentileCont,
+    SqlPercentileDisc, SqlPosition, SqlPower, SqlReplace, SqlRight, SqlRound, SqlSha224, SqlSha256,
+    SqlSha384, SqlSha512, SqlSqrt, SqlStartsWith, SqlStddev, SqlStringAgg, SqlSubstring, SqlSum,
+    SqlText, SqlTrim, SqlType, SqlUnnest, SqlUpper, SqlVariance, StatisticalSqlType,
+    SummableSqlType, TemporalSqlType, TimestampSqlType,
 };
+use crate::util::{TypedFalse, TypesEqual};
 
 macro_rules! define_one_expr_arg_sql_function {
-    {$fn_name: ident, $expr_type_name: ident $(,$where_path: path : $where_condition: path)?} => {
+    {$fn_name: ident, $expr_type_name: ident $(,$where_path: ty : $where_condition: path)?} => {
         pub fn $fn_name<S: SelectableTables, E: SqlExpression<S>>(expr: E) -> $expr_type_name<S, E>
             $(
                 where $where_path: $where_condition
@@ -15,11 +26,264 @@ macro_rules! define_one_expr_arg_sql_function {
     };
 }
 
+/// Declares a custom sql function, which can be used for calling sql functions
+/// that are not supported by the orm, like functions defined by extensions.
+///
+/// This generates an expression struct for the function, and a free function
+/// with the given name which creates it. The arguments of the function are
+/// each declared with a name, a generic type and the sql type that they must
+/// have. The declaration ends with the name of the expression struct and the
+/// name of the function in sql.
+///
+/// Aggregate functions are declared by writing `aggregate` before `fn`, and
+/// support the same modifiers as the builtin aggregate functions, like
+/// `distinct` and `filter`.
+///
+/// Please note that the orm can't verify the declaration, so it is up to you
+/// to make sure that the function exists and that it accepts and returns the
+/// specified sql types.
+///
+/// # Example
+/// ```rust,no_run
+/// use gorm::{
+///     execution::DatabaseConnectionPool,
+///     select_values,
+///     sql::{OrderableSqlExpression, SqlF32, SqlI32, SqlOption, SqlText},
+///     sql_function,
+///     statements::{Filter, LoadSingleColumnSqlStatment, SelectFrom, SelectValues},
+///     Table,
+/// };
+///
+/// #[derive(Debug, Table)]
+/// pub struct Person {
+///     pub id: i32,
+///     pub name: String,
+///     pub age: i32,
+/// }
+///
+/// sql_function! {
+///     /// Returns a number which indicates how similar the two strings are.
+///     pub fn similarity(lhs: L: SqlText, rhs: R: SqlText) -> SqlF32 {
+///         Similarity, "similarity"
+///     }
+/// }
+///
+/// sql_function! {
+///     /// Returns the bitwise xor of all values returned from the query.
+///     pub aggregate fn bit_xor(value: V: SqlI32) -> SqlOption<SqlI32> {
+///         BitXor, "BIT_XOR"
+///     }
+/// }
+///
+/// async fn find_age_xor(pool: &DatabaseConnectionPool) -> gorm::Result<Option<i32>> {
+///     let ages_xor = person::table
+///         .find()
+///         .filter(similarity(person::name, "James").greater_than(0.5f32))
+///         .select(select_values!(bit_xor(person::age) as age_xor))
+///         .load_all_values(pool)
+///         .await?;
+///     Ok(ages_xor[0])
+/// }
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! sql_function {
+    {
+        $(#[$attr: meta])*
+        $vis: vis aggregate fn $fn_name: ident($($field: ident: $generic: ident: $arg_sql_type: ty),* $(,)?)
+            -> $output_sql_type: ty
+        {
+            $type_name: ident, $sql_name: literal
+        }
+    } => {
+        $crate::sql_function! {
+            @common
+            $(#[$attr])*
+            $vis fn $fn_name($($field: $generic: $arg_sql_type),*) -> $output_sql_type {
+                $type_name, $sql_name
+            }
+        }
+
+        impl<S: $crate::sql::SelectableTables, $($generic: $crate::sql::SqlExpression<S, SqlType = $arg_sql_type>),*>
+            $crate::sql::SqlExpression<S> for $type_name<S, $($generic),*>
+        {
+            type RustType = <$output_sql_type as $crate::sql::SqlType>::RustType;
+            type SqlType = $output_sql_type;
+
+            const IS_AGGREGATE: bool = true;
+
+            fn write_sql_string<'s, 'a>(
+                &'s self,
+                f: &mut ::std::string::String,
+                parameter_binder: &mut $crate::sql::ParameterBinder<'a>,
+            ) -> ::std::fmt::Result
+            where
+                's: 'a,
+            {
+                $crate::sql::AggregateSqlExpression::write_aggregate_sql_string(self, f, parameter_binder)
+            }
+        }
+
+        impl<S: $crate::sql::SelectableTables, $($generic: $crate::sql::SqlExpression<S, SqlType = $arg_sql_type>),*>
+            $crate::sql::AggregateSqlExpression<S> for $type_name<S, $($generic),*>
+        {
+            type HasDistinct = $crate::util::TypedFalse;
+            type HasFilter = $crate::util::TypedFalse;
+            type HasOrderBy = $crate::util::TypedFalse;
+            type IsNullWithoutRows = <$output_sql_type as $crate::sql::SqlType>::IsNull;
+
+            fn write_function_name(&self, f: &mut ::std::string::String) -> ::std::fmt::Result {
+                f.push_str($sql_name);
+                Ok(())
+            }
+
+            fn write_arguments<'s, 'a>(
+                &'s self,
+                f: &mut ::std::string::String,
+                parameter_binder: &mut $crate::sql::ParameterBinder<'a>,
+            ) -> ::std::fmt::Result
+            where
+                's: 'a,
+            {
+                $crate::sql_function!(@write_arguments self, f, parameter_binder; $($field),*)
+            }
+
+            fn write_filter_clause<'s, 'a>(
+                &'s self,
+                _f: &mut ::std::string::String,
+                _parameter_binder: &mut $crate::sql::ParameterBinder<'a>,
+            ) -> ::std::fmt::Result
+            where
+                's: 'a,
+            {
+                Ok(())
+            }
+        }
+    };
+    {
+        $(#[$attr: meta])*
+        $vis: vis fn $fn_name: ident($($field: ident: $generic: ident: $arg_sql_type: ty),* $(,)?)
+            -> $output_sql_type: ty
+        {
+            $type_name: ident, $sql_name: literal
+        }
+    } => {
+        $crate::sql_function! {
+            @common
+            $(#[$attr])*
+            $vis fn $fn_name($($field: $generic: $arg_sql_type),*) -> $output_sql_type {
+                $type_name, $sql_name
+            }
+        }
+
+        impl<S: $crate::sql::SelectableTables, $($generic: $crate::sql::SqlExpression<S, SqlType = $arg_sql_type>),*>
+            $crate::sql::SqlExpression<S> for $type_name<S, $($generic),*>
+        {
+            type RustType = <$output_sql_type as $crate::sql::SqlType>::RustType;
+            type SqlType = $output_sql_type;
+
+            const IS_AGGREGATE: bool = false $(|| $generic::IS_AGGREGATE)*;
+
+            fn write_sql_string<'s, 'a>(
+                &'s self,
+                f: &mut ::std::string::String,
+                parameter_binder: &mut $crate::sql::ParameterBinder<'a>,
+            ) -> ::std::fmt::Result
+            where
+                's: 'a,
+            {
+                f.push_str($sql_name);
+                f.push('(');
+                $crate::sql_function!(@write_arguments self, f, parameter_binder; $($field),*)?;
+                f.push(')');
+                Ok(())
+            }
+        }
+    };
+    {
+        @common
+        $(#[$attr: meta])*
+        $vis: vis fn $fn_name: ident($($field: ident: $generic: ident: $arg_sql_type: ty),*)
+            -> $output_sql_type: ty
+        {
+            $type_name: ident, $sql_name: literal
+        }
+    } => {
+        #[doc = concat!("An sql expression which calls the `", $sql_name, "` sql function.")]
+        $vis struct $type_name<S: $crate::sql::SelectableTables, $($generic: $crate::sql::SqlExpression<S, SqlType = $arg_sql_type>),*> {
+            $($field: $generic,)*
+            phantom: ::std::marker::PhantomData<S>,
+        }
+
+        // functions without arguments don't need a `Default` implementation.
+        #[allow(clippy::new_without_default)]
+        impl<S: $crate::sql::SelectableTables, $($generic: $crate::sql::SqlExpression<S, SqlType = $arg_sql_type>),*>
+            $type_name<S, $($generic),*>
+        {
+            pub fn new($($field: $generic),*) -> Self {
+                Self {
+                    $($field,)*
+                    phantom: ::std::marker::PhantomData,
+                }
+            }
+        }
+
+        $(#[$attr])*
+        $vis fn $fn_name<S: $crate::sql::SelectableTables, $($generic: $crate::sql::SqlExpression<S, SqlType = $arg_sql_type>),*>(
+            $($field: $generic),*
+        ) -> $type_name<S, $($generic),*> {
+            $type_name::new($($field),*)
+        }
+    };
+    {@write_arguments $self: ident, $f: ident, $parameter_binder: ident;} => {{
+        let _ = &$f;
+        let _ = &$parameter_binder;
+        Ok(())
+    }};
+    {@write_arguments $self: ident, $f: ident, $parameter_binder: ident; $first: ident $(, $rest: ident)*} => {{
+        $crate::sql::SqlExpression::write_sql_string(&$self.$first, $f, $parameter_binder)?;
+        $(
+            $f.push_str(", ");
+            $crate::sql::SqlExpression::write_sql_string(&$self.$rest, $f, $parameter_binder)?;
+        )*
+        Ok(())
+    }};
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+crate::sql_function! {
+    /// Returns an expression which evaluates to the time at which the current
+    /// transaction has started.
+    pub fn now() -> crate::sql::SqlTimestampTz {
+        SqlNow, "NOW"
+    }
+}
+
 define_one_expr_arg_sql_function! {count, SqlCount}
 define_one_expr_arg_sql_function! {average, SqlAverage, E::SqlType: AverageableSqlType}
 define_one_expr_arg_sql_function! {sum, SqlSum, E::SqlType: SummableSqlType}
 define_one_expr_arg_sql_function! {max, SqlMax, E::SqlType: OrderableSqlType}
 define_one_expr_arg_sql_function! {min, SqlMin, E::SqlType: OrderableSqlType}
+define_one_expr_arg_sql_function! {lower, SqlLower, E::SqlType: SqlType<NonNullSqlType = SqlText>}
+define_one_expr_arg_sql_function! {upper, SqlUpper, E::SqlType: SqlType<NonNullSqlType = SqlText>}
+define_one_expr_arg_sql_function! {length, SqlLength, E::SqlType: SqlType<NonNullSqlType = SqlText>}
+define_one_expr_arg_sql_function! {trim, SqlTrim, E::SqlType: SqlType<NonNullSqlType = SqlText>}
+define_one_expr_arg_sql_function! {abs, SqlAbs, <E::SqlType as SqlType>::NonNullSqlType: MathFunctionSqlType}
+define_one_expr_arg_sql_function! {ceil, SqlCeil, <E::SqlType as SqlType>::NonNullSqlType: MathFunctionSqlType}
+define_one_expr_arg_sql_function! {floor, SqlFloor, <E::SqlType as SqlType>::NonNullSqlType: MathFunctionSqlType}
+define_one_expr_arg_sql_function! {sqrt, SqlSqrt, <E::SqlType as SqlType>::NonNullSqlType: MathFunctionSqlType}
+define_one_expr_arg_sql_function! {array_agg, SqlArrayAgg, E::SqlType: ArrayElementSqlType}
+define_one_expr_arg_sql_function! {bool_and, SqlBoolAnd, E::SqlType: SqlType<NonNullSqlType = SqlBool>}
+define_one_expr_arg_sql_function! {bool_or, SqlBoolOr, E::SqlType: SqlType<NonNullSqlType = SqlBool>}
+define_one_expr_arg_sql_function! {stddev, SqlStddev, E::SqlType: StatisticalSqlType}
+define_one_expr_arg_sql_function! {variance, SqlVariance, E::SqlType: StatisticalSqlType}
+define_one_expr_arg_sql_function! {array_length, SqlArrayLength, <E::SqlType as SqlType>::NonNullSqlType: ArraySqlType}
+define_one_expr_arg_sql_function! {unnest, SqlUnnest, <E::SqlType as SqlType>::NonNullSqlType: ArraySqlType}
+define_one_expr_arg_sql_function! {md5, SqlMd5, E::SqlType: SqlType<NonNullSqlType = SqlBytea>}
+define_one_expr_arg_sql_function! {sha224, SqlSha224, E::SqlType: SqlType<NonNullSqlType = SqlBytea>}
+define_one_expr_arg_sql_function! {sha256, SqlSha256, E::SqlType: SqlType<NonNullSqlType = SqlBytea>}
+define_one_expr_arg_sql_function! {sha384, SqlSha384, E::SqlType: SqlType<NonNullSqlType = SqlBytea>}
+define_one_expr_arg_sql_function! {sha512, SqlSha512, E::SqlType: SqlType<NonNullSqlType = SqlBytea>}
 
 /// Returns an expression which evaluates to the amount of all rows returned
 /// from the query.
@@ -34,3 +298,365 @@ pub fn count_rows() -> SqlCountRows {
 pub fn not<S: SelectableTables, E: SqlExpression<S, SqlType = SqlBool>>(expr: E) -> SqlNot<S, E> {
     SqlNot::new(expr)
 }
+
+/// Returns an expression which evaluates to the part of the given string which
+/// starts at the given position, counting from 1, and contains at most the
+/// given amount of characters.
+pub fn substring<
+    S: SelectableTables,
+    E: SqlExpression<S>,
+    F: SqlExpression<S>,
+    L: SqlExpression<S>,
+>(
+    expr: E,
+    start: F,
+    length: L,
+) -> SqlSubstring<S, E, F, L>
+where
+    E::SqlType: SqlType<NonNullSqlType = SqlText>,
+    F::SqlType: SqlType<NonNullSqlType = SqlI32>,
+    L::SqlType: SqlType<NonNullSqlType = SqlI32>,
+{
+    SqlSubstring::new(expr, start, length)
+}
+
+/// Returns an expression which evaluates to the given string with all
+/// occurences of `from` replaced with `to`.
+pub fn replace<S: SelectableTables, E: SqlExpression<S>, F: SqlExpression<S>, T: SqlExpression<S>>(
+    expr: E,
+    from: F,
+    to: T,
+) -> SqlReplace<S, E, F, T>
+where
+    E::SqlType: SqlType<NonNullSqlType = SqlText>,
+    F::SqlType: SqlType<NonNullSqlType = SqlText>,
+    T::SqlType: SqlType<NonNullSqlType = SqlText>,
+{
+    SqlReplace::new(expr, from, to)
+}
+
+/// Returns an expression which evaluates to the concatenation of the given
+/// strings.
+///
+/// This will be translated to the `||` operator when converted to sql.
+pub fn concat<S: SelectableTables, Lhs: SqlExpression<S>, Rhs: SqlExpression<S>>(
+    lhs: Lhs,
+    rhs: Rhs,
+) -> SqlConcat<S, Lhs, Rhs>
+where
+    Lhs::SqlType: SqlType<NonNullSqlType = SqlText>,
+    Rhs::SqlType: SqlType<NonNullSqlType = SqlText>,
+{
+    SqlConcat::new(lhs, rhs)
+}
+
+/// Returns an expression which evaluates to the first `n` characters of the
+/// given string.
+pub fn left<S: SelectableTables, E: SqlExpression<S>, N: SqlExpression<S>>(
+    expr: E,
+    n: N,
+) -> SqlLeft<S, E, N>
+where
+    E::SqlType: SqlType<NonNullSqlType = SqlText>,
+    N::SqlType: SqlType<NonNullSqlType = SqlI32>,
+{
+    SqlLeft::new(expr, n)
+}
+
+/// Returns an expression which evaluates to the last `n` characters of the
+/// given string.
+pub fn right<S: SelectableTables, E: SqlExpression<S>, N: SqlExpression<S>>(
+    expr: E,
+    n: N,
+) -> SqlRight<S, E, N>
+where
+    E::SqlType: SqlType<NonNullSqlType = SqlText>,
+    N::SqlType: SqlType<NonNullSqlType = SqlI32>,
+{
+    SqlRight::new(expr, n)
+}
+
+/// Returns a condition which will be true if the given string starts with the
+/// given prefix.
+pub fn starts_with<S: SelectableTables, E: SqlExpression<S>, P: SqlExpression<S>>(
+    expr: E,
+    prefix: P,
+) -> SqlStartsWith<S, E, P>
+where
+    E::SqlType: SqlType<NonNullSqlType = SqlText>,
+    P::SqlType: SqlType<NonNullSqlType = SqlText>,
+{
+    SqlStartsWith::new(expr, prefix)
+}
+
+/// Returns an expression which evaluates to the position of the first
+/// occurence of the given substring in the given string, counting from 1, or
+/// to 0 if the string doesn't contain it.
+///
+/// This will be translated to `POSITION(substring IN expr)` when converted to
+/// sql.
+pub fn position<S: SelectableTables, Sub: SqlExpression<S>, E: SqlExpression<S>>(
+    substring: Sub,
+    expr: E,
+) -> SqlPosition<S, Sub, E>
+where
+    Sub::SqlType: SqlType<NonNullSqlType = SqlText>,
+    E::SqlType: SqlType<NonNullSqlType = SqlText>,
+{
+    SqlPosition::new(substring, expr)
+}
+
+/// Returns an expression which evaluates to the given base raised to the power
+/// of the given exponent.
+pub fn power<S: SelectableTables, B: SqlExpression<S>, X: SqlExpression<S>>(
+    base: B,
+    exponent: X,
+) -> SqlPower<S, B, X>
+where
+    <B::SqlType as SqlType>::NonNullSqlType: MathFunctionSqlType,
+    <X::SqlType as SqlType>::NonNullSqlType: MathFunctionSqlType<
+        OutputSqlType = <<B::SqlType as SqlType>::NonNullSqlType as MathFunctionSqlType>::OutputSqlType,
+    >,
+{
+    SqlPower::new(base, exponent)
+}
+
+/// Returns an expression which evaluates to the given numeric expression
+/// rounded to the given amount of decimal digits.
+pub fn round<S: SelectableTables, E: SqlExpression<S>, D: SqlExpression<S>>(
+    expr: E,
+    digits: D,
+) -> SqlRound<S, E, D>
+where
+    E::SqlType: SqlType<NonNullSqlType = SqlNumeric>,
+    D::SqlType: SqlType<NonNullSqlType = SqlI32>,
+{
+    SqlRound::new(expr, digits)
+}
+
+/// Returns an expression which evaluates to the greater value out of the given
+/// expressions, ignoring null values.
+pub fn greatest<S: SelectableTables, Lhs: SqlExpression<S>, Rhs: SqlExpression<S>>(
+    lhs: Lhs,
+    rhs: Rhs,
+) -> SqlGreatest<S, Lhs, Rhs>
+where
+    Lhs::SqlType: OrderableSqlType + SqlComparable<Rhs::SqlType>,
+{
+    SqlGreatest::new(lhs, rhs)
+}
+
+/// Returns an expression which evaluates to the lower value out of the given
+/// expressions, ignoring null values.
+pub fn least<S: SelectableTables, Lhs: SqlExpression<S>, Rhs: SqlExpression<S>>(
+    lhs: Lhs,
+    rhs: Rhs,
+) -> SqlLeast<S, Lhs, Rhs>
+where
+    Lhs::SqlType: OrderableSqlType + SqlComparable<Rhs::SqlType>,
+{
+    SqlLeast::new(lhs, rhs)
+}
+
+/// Returns an expression which evaluates to the first of the given expressions
+/// which is not null.
+///
+/// More expressions can be added using [`SqlCoalesce::or_else`], for example
+/// `coalesce(a, b).or_else(c)` evaluates to `COALESCE(a, b, c)`. If the last
+/// expression is not nullable, the result is not nullable.
+pub fn coalesce<S: SelectableTables, Lhs: SqlExpression<S>, Rhs: SqlExpression<S>>(
+    lhs: Lhs,
+    rhs: Rhs,
+) -> SqlCoalesce<S, SqlCoalesceFirstArgument<S, Lhs>, Rhs>
+where
+    Lhs::SqlType: SqlType<NonNullSqlType = <Rhs::SqlType as SqlType>::NonNullSqlType>,
+{
+    SqlCoalesce::new(lhs, rhs)
+}
+
+/// Returns an expression which evaluates to null if the given expressions are
+/// equal, or to the first expression otherwise.
+pub fn nullif<S: SelectableTables, Lhs: SqlExpression<S>, Rhs: SqlExpression<S>>(
+    lhs: Lhs,
+    rhs: Rhs,
+) -> SqlNullIf<S, Lhs, Rhs>
+where
+    Lhs::SqlType: SqlType<NonNullSqlType = <Rhs::SqlType as SqlType>::NonNullSqlType>,
+    (
+        <<Lhs::SqlType as SqlType>::NonNullSqlType as SqlType>::IsNull,
+        TypedFalse,
+    ): TypesEqual,
+{
+    SqlNullIf::new(lhs, rhs)
+}
+
+/// Returns an sql `CASE` expression with a single branch which evaluates to
+/// the given value if the given condition is true.
+///
+/// More branches can be added using [`SqlCaseWhen::when`], and the value for
+/// the case where none of the conditions are true must be provided using
+/// [`SqlCaseWhen::otherwise`]. The values of all branches must have the same
+/// sql type.
+pub fn case_when<
+    S: SelectableTables,
+    C: SqlExpression<S, SqlType = SqlBool>,
+    V: SqlExpression<S>,
+>(
+    condition: C,
+    value: V,
+) -> SqlCaseWhen<S, SqlCaseWhenNoBranches<V::SqlType>, C, V> {
+    SqlCaseWhen::new(condition, value)
+}
+
+/// Returns an expression which evaluates to the concatenation of the strings
+/// returned from the query, separated by the given delimiter, or to null if all
+/// of the strings are null.
+pub fn string_agg<
+    S: SelectableTables,
+    E: SqlExpression<S>,
+    D: SqlExpression<S, SqlType = SqlText>,
+>(
+    expr: E,
+    delimiter: D,
+) -> SqlStringAgg<S, E, D>
+where
+    E::SqlType: SqlType<NonNullSqlType = SqlText>,
+{
+    SqlStringAgg::new(expr, delimiter)
+}
+
+/// Returns an expression which evaluates to the continuous percentile of the
+/// values of the given expression returned from the query, interpolating
+/// between adjacent values if needed, or to null if all of the values are null.
+///
+/// This will be translated to `PERCENTILE_CONT(fraction) WITHIN GROUP (ORDER BY
+/// expr)` when converted to sql.
+pub fn percentile_cont<
+    S: SelectableTables,
+    F: SqlExpression<S, SqlType = SqlF64>,
+    E: SqlExpression<S>,
+>(
+    fraction: F,
+    expr: E,
+) -> SqlPercentileCont<S, F, E>
+where
+    E::SqlType: ContinuousPercentileSqlType,
+{
+    SqlPercentileCont::new(fraction, expr)
+}
+
+/// Returns an expression which evaluates to the first value of the given
+/// expression returned from the query whose position in the ordered values is
+/// greater than or equal to the given fraction, or to null if all of the values
+/// are null.
+///
+/// This will be translated to `PERCENTILE_DISC(fraction) WITHIN GROUP (ORDER BY
+/// expr)` when converted to sql.
+pub fn percentile_disc<
+    S: SelectableTables,
+    F: SqlExpression<S, SqlType = SqlF64>,
+    E: SqlExpression<S>,
+>(
+    fraction: F,
+    expr: E,
+) -> SqlPercentileDisc<S, F, E>
+where
+    E::SqlType: OrderableSqlType,
+    (
+        <<E::SqlType as SqlType>::NonNullSqlType as SqlType>::IsNull,
+        TypedFalse,
+    ): TypesEqual,
+{
+    SqlPercentileDisc::new(fraction, expr)
+}
+
+/// Returns an expression which evaluates to the given field of the given date
+/// and time expression, like its year or its hour.
+///
+/// This will be translated to `DATE_PART('field', expr)` when converted to sql.
+pub fn extract<S: SelectableTables, E: SqlExpression<S>>(
+    field: DateTimeField,
+    expr: E,
+) -> SqlExtract<S, E>
+where
+    <E::SqlType as SqlType>::NonNullSqlType: TemporalSqlType,
+{
+    SqlExtract::new(field, expr)
+}
+
+/// Returns an expression which evaluates to the given timestamp truncated to
+/// the given precision.
+pub fn date_trunc<S: SelectableTables, E: SqlExpression<S>>(
+    field: DateTimeField,
+    expr: E,
+) -> SqlDateTrunc<S, E>
+where
+    <E::SqlType as SqlType>::NonNullSqlType: TimestampSqlType,
+{
+    SqlDateTrunc::new(field, expr)
+}
+
+/// Returns an expression which evaluates to the interval between the second
+/// timestamp and the first one, in years, months and days rather than just
+/// days.
+pub fn age<S: SelectableTables, Lhs: SqlExpression<S>, Rhs: SqlExpression<S>>(
+    lhs: Lhs,
+    rhs: Rhs,
+) -> SqlAge<S, Lhs, Rhs>
+where
+    Lhs::SqlType: SqlType<NonNullSqlType = <Rhs::SqlType as SqlType>::NonNullSqlType>,
+    <Lhs::SqlType as SqlType>::NonNullSqlType: TimestampSqlType,
+{
+    SqlAge::new(lhs, rhs)
+}
+
+/// Returns an expression which evaluates to the given json value with the
+/// value at the given path replaced by the given value.
+pub fn jsonb_set<
+    S: SelectableTables,
+    J: SqlExpression<S>,
+    P: SqlExpression<S>,
+    V: SqlExpression<S>,
+>(
+    json: J,
+    path: P,
+    value: V,
+) -> SqlJsonbSet<S, J, P, V>
+where
+    J::SqlType: SqlType<NonNullSqlType = SqlJsonb>,
+    P::SqlType: SqlType<NonNullSqlType = SqlArray<SqlText>>,
+    V::SqlType: SqlType<NonNullSqlType = SqlJsonb>,
+{
+    SqlJsonbSet::new(json, path, value)
+}
+
+/// Returns an expression which evaluates to a `jsonb` object with a single
+/// field with the given key and value.
+///
+/// More fields can be added using [`SqlJsonbBuildObject::field`].
+pub fn jsonb_build_object<
+    S: SelectableTables,
+    K: SqlExpression<S, SqlType = SqlText>,
+    V: SqlExpression<S>,
+>(
+    key: K,
+    value: V,
+) -> SqlJsonbBuildObject<S, SqlJsonbObjectNoFields, K, V> {
+    SqlJsonbBuildObject::new(key, value)
+}
+
+/// Returns an expression which evaluates to the given array with the given
+/// element appended to its end.
+pub fn array_append<S: SelectableTables, A: SqlExpression<S>, E: SqlExpression<S>>(
+    array: A,
+    element: E,
+) -> SqlArrayAppend<S, A, E>
+where
+    <A::SqlType as SqlType>::NonNullSqlType: ArraySqlType,
+    (
+        E::SqlType,
+        <<A::SqlType as SqlType>::NonNullSqlType as ArraySqlType>::ElementSqlType,
+    ): TypesEqual,
+{
+    SqlArrayAppend::new(array, element)
+}
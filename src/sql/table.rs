@@ -1,10 +1,11 @@
 use super::FieldNameCharsConsListItem;
 use crate::{
-    sql::{FieldsConsListItem, IntoSqlType, SqlType},
+    sql::{FieldsConsListItem, IntoSqlType, SelectStatementInsertable, SqlType},
     statements::{
-        CreateTableStatement, DropTableStatement, EmptyDeleteStatement, EmptyUpdateStatement,
+        CreateTableStatement, DropTableStatement, EmptyDeleteStatement, EmptyInsertStatement,
+        EmptyUpdateStatement, SelectStatement,
     },
-    util::TypesEqual,
+    util::{TypedFalse, TypedTrue, TypesEqual},
 };
 
 /// A table in the database.
@@ -12,6 +13,10 @@ pub trait Table: Sized + 'static {
     /// A type used to identify the fields of the table.
     type Fields: FieldsConsListItem;
 
+    /// A type used to identify the fields of the table, excluding the `id`
+    /// field.
+    type FieldsWithoutId: FieldsConsListItem;
+
     /// Information about each field in the table.
     const FIELDS: &'static [TableField];
 
@@ -107,6 +112,43 @@ pub trait TableMarker: Sized + 'static {
     fn update(self) -> EmptyUpdateStatement<Self::Table> {
         EmptyUpdateStatement::new()
     }
+
+    /// Returns an insert statement which inserts the records returned by the
+    /// given select statement into this table.
+    ///
+    /// The values selected by the select statement must match the fields of
+    /// this table other than the `id` field, which will be generated by the
+    /// database.
+    fn insert_select<S: SelectStatement>(
+        self,
+        select_statement: S,
+    ) -> EmptyInsertStatement<SelectStatementInsertable<Self::Table, S, TypedFalse>>
+    where
+        (
+            <S as SelectStatement>::OutputFields,
+            <Self::Table as Table>::FieldsWithoutId,
+        ): TypesEqual,
+    {
+        EmptyInsertStatement::new(SelectStatementInsertable::new(select_statement))
+    }
+
+    /// Returns an insert statement which inserts the records returned by the
+    /// given select statement into this table, including their `id` field.
+    ///
+    /// The values selected by the select statement must match all the fields
+    /// of this table.
+    fn insert_select_with_id<S: SelectStatement>(
+        self,
+        select_statement: S,
+    ) -> EmptyInsertStatement<SelectStatementInsertable<Self::Table, S, TypedTrue>>
+    where
+        (
+            <S as SelectStatement>::OutputFields,
+            <Self::Table as Table>::Fields,
+        ): TypesEqual,
+    {
+        EmptyInsertStatement::new(SelectStatementInsertable::new_with_id(select_statement))
+    }
 }
 
 /// Indicates that some table has a foreign key to some other table
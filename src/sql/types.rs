@@ -1,5 +1,6 @@
 use rust_decimal::Decimal;
 
+use super::{Bytea, Interval, Json, Jsonb};
 use crate::util::{TypedBool, TypedFalse, TypedTrue, TypesEqual};
 
 /// An sql type.
@@ -88,13 +89,47 @@ define_sql_type! { SqlF32, "real" => f32 }
 define_sql_type! { SqlF64, "double precision" => f64 }
 define_sql_type! { SqlNumeric, "numeric" => Decimal }
 define_sql_type! { SqlText, "text" => String }
+define_sql_type! { SqlInterval, "interval" => Interval }
+define_sql_type! { SqlJsonb, "jsonb" => serde_json::Value }
+define_sql_type! { SqlBytea, "bytea" => Vec<u8> }
 define_sql_type! { serial Serial16, "smallserial" => i16  }
 define_sql_type! { serial Serial32, "serial" => i32 }
 define_sql_type! { serial Serial64, "bigserial" => i64 }
 
+#[cfg(feature = "uuid")]
+define_sql_type! { SqlUuid, "uuid" => uuid::Uuid }
+
+// uuid primary keys are generated by the database when inserting a row without
+// an id, just like serial primary keys.
+#[cfg(feature = "uuid")]
+define_sql_type! { serial SerialUuid, "uuid DEFAULT gen_random_uuid()" => uuid::Uuid }
+
 impl<'a> IntoSqlType for &'a str {
     type SqlType = SqlText;
 }
+impl IntoSqlType for &[u8] {
+    type SqlType = SqlBytea;
+}
+impl IntoSqlType for Bytea {
+    type SqlType = SqlBytea;
+}
+
+/// The sql `json` type, which stores json values as text, as opposed to the
+/// `jsonb` type which stores them in a decomposed binary format.
+pub struct SqlJson;
+impl SqlType for SqlJson {
+    type IsNull = TypedFalse;
+    type NonNullSqlType = Self;
+    type RustType = Json<serde_json::Value>;
+
+    const SQL_NAME: &'static str = "json";
+}
+impl<T> IntoSqlType for Json<T> {
+    type SqlType = SqlJson;
+}
+impl<T> IntoSqlType for Jsonb<T> {
+    type SqlType = SqlJsonb;
+}
 
 /// A wrapper around a non-nullable sql type which makes it nullable.
 pub struct SqlOption<T: SqlType>(T)
@@ -118,6 +153,86 @@ where
     type SqlType = SqlOption<T::SqlType>;
 }
 
+/// An sql type which can be an element of an sql array.
+pub trait ArrayElementSqlType: SqlType {
+    /// The sql string representation of an array of this type.
+    const ARRAY_SQL_NAME: &'static str;
+}
+
+macro_rules! mark_array_element_sql_types {
+    {$($t: ty: $array_sql_name: expr),*} => {
+        $(
+            impl ArrayElementSqlType for $t {
+                const ARRAY_SQL_NAME: &'static str = $array_sql_name;
+            }
+        )*
+    };
+}
+
+mark_array_element_sql_types! {
+    SqlBool: "boolean[]",
+    SqlI16: "smallint[]",
+    SqlI32: "integer[]",
+    SqlI64: "bigint[]",
+    SqlF32: "real[]",
+    SqlF64: "double precision[]",
+    SqlNumeric: "numeric[]",
+    SqlText: "text[]",
+    SqlInterval: "interval[]",
+    SqlJson: "json[]",
+    SqlJsonb: "jsonb[]",
+    SqlBytea: "bytea[]"
+}
+
+#[cfg(feature = "uuid")]
+mark_array_element_sql_types! {SqlUuid: "uuid[]"}
+
+impl<T: ArrayElementSqlType> ArrayElementSqlType for SqlOption<T>
+where
+    (T::IsNull, TypedFalse): TypesEqual,
+{
+    const ARRAY_SQL_NAME: &'static str = T::ARRAY_SQL_NAME;
+}
+
+/// An sql array whose elements are of type `T`.
+pub struct SqlArray<T: ArrayElementSqlType>(T);
+
+impl<T: ArrayElementSqlType> SqlType for SqlArray<T> {
+    type IsNull = TypedFalse;
+    type NonNullSqlType = Self;
+    type RustType = Vec<T::RustType>;
+
+    const SQL_NAME: &'static str = T::ARRAY_SQL_NAME;
+}
+impl<T: IntoSqlType> IntoSqlType for Vec<T>
+where
+    T::SqlType: ArrayElementSqlType,
+{
+    type SqlType = SqlArray<T::SqlType>;
+}
+
+/// An sql array type, whose elements can be accessed.
+pub trait ArraySqlType: SqlType<IsNull = TypedFalse> {
+    /// The sql type of the elements of the array.
+    type ElementSqlType: ArrayElementSqlType;
+}
+impl<T: ArrayElementSqlType> ArraySqlType for SqlArray<T> {
+    type ElementSqlType = T;
+}
+
+/// A marker trait which represents that a value of type `Self` can be compared
+/// with a value of type `Rhs`.
+///
+/// A type can be compared with itself, and a nullable type can be compared with
+/// its non-nullable counterpart, and vice versa.
+pub trait SqlComparable<Rhs> {}
+
+impl<T: SqlType> SqlComparable<T> for T {}
+
+impl<T: SqlType> SqlComparable<T> for SqlOption<T> where (T::IsNull, TypedFalse): TypesEqual {}
+
+impl<T: SqlType> SqlComparable<SqlOption<T>> for T where (T::IsNull, TypedFalse): TypesEqual {}
+
 /// An sql type which can be ordered, which means it can be compared with other
 /// values of the same type.
 pub trait OrderableSqlType {}
@@ -130,7 +245,15 @@ macro_rules! mark_sql_types {
     };
 }
 
-mark_sql_types! {OrderableSqlType => SqlI16, SqlI32, SqlI64, SqlF32, SqlF64, SqlNumeric, Serial16, Serial32, Serial64, SqlText}
+mark_sql_types! {OrderableSqlType => SqlI16, SqlI32, SqlI64, SqlF32, SqlF64, SqlNumeric, Serial16, Serial32, Serial64, SqlText, SqlInterval, SqlBytea}
+
+#[cfg(feature = "uuid")]
+mark_sql_types! {OrderableSqlType => SqlUuid}
+
+impl<T: SqlType + OrderableSqlType> OrderableSqlType for SqlOption<T> where
+    (T::IsNull, TypedFalse): TypesEqual
+{
+}
 
 /// An sql type which can be averaged, which means that we can find the average
 /// of multiple values of this type.
@@ -155,6 +278,44 @@ macro_rules! mark_sql_types_with_output_type {
 }
 
 mark_sql_types_with_output_type! {AverageableSqlType =>
+    SqlI16: SqlNumeric,
+    SqlI32: SqlNumeric,
+    SqlI64: SqlNumeric,
+    SqlF32: SqlF64,
+    SqlF64: SqlF64,
+    SqlNumeric: SqlNumeric,
+    Serial16: SqlNumeric,
+    Serial32: SqlNumeric,
+    Serial64: SqlNumeric,
+    SqlInterval: SqlInterval
+}
+
+/// An sql type which can be passed to math functions like `CEIL`, `FLOOR`,
+/// `POWER` and `SQRT`, which evaluate to either a `double precision` or a
+/// `numeric` value.
+pub trait MathFunctionSqlType {
+    type OutputSqlType: SqlType<IsNull = TypedFalse>;
+}
+
+mark_sql_types_with_output_type! {MathFunctionSqlType =>
+    SqlI16: SqlF64,
+    SqlI32: SqlF64,
+    SqlI64: SqlF64,
+    SqlF32: SqlF64,
+    SqlF64: SqlF64,
+    SqlNumeric: SqlNumeric,
+    Serial16: SqlF64,
+    Serial32: SqlF64,
+    Serial64: SqlF64
+}
+
+/// An sql type whose statistical properties, like its standard deviation and
+/// variance, can be computed over multiple values of this type.
+pub trait StatisticalSqlType {
+    type OutputSqlType: SqlType<IsNull = TypedFalse>;
+}
+
+mark_sql_types_with_output_type! {StatisticalSqlType =>
     SqlI16: SqlNumeric,
     SqlI32: SqlNumeric,
     SqlI64: SqlNumeric,
@@ -166,6 +327,25 @@ mark_sql_types_with_output_type! {AverageableSqlType =>
     Serial64: SqlNumeric
 }
 
+/// An sql type which can be interpolated to find a continuous percentile of
+/// multiple values of this type.
+pub trait ContinuousPercentileSqlType {
+    type OutputSqlType: SqlType<IsNull = TypedFalse>;
+}
+
+mark_sql_types_with_output_type! {ContinuousPercentileSqlType =>
+    SqlI16: SqlF64,
+    SqlI32: SqlF64,
+    SqlI64: SqlF64,
+    SqlF32: SqlF64,
+    SqlF64: SqlF64,
+    SqlNumeric: SqlF64,
+    Serial16: SqlF64,
+    Serial32: SqlF64,
+    Serial64: SqlF64,
+    SqlInterval: SqlInterval
+}
+
 mark_sql_types_with_output_type! {SummableSqlType =>
     SqlI16: SqlI64,
     SqlI32: SqlI64,
@@ -175,7 +355,8 @@ mark_sql_types_with_output_type! {SummableSqlType =>
     SqlNumeric: SqlNumeric,
     Serial16: SqlI64,
     Serial32: SqlI64,
-    Serial64: SqlNumeric
+    Serial64: SqlNumeric,
+    SqlInterval: SqlInterval
 }
 
 macro_rules! mark_sql_types_with_rhs {
@@ -224,3 +405,111 @@ mark_all_number_types_with_rhs! {SqlAdd}
 mark_all_number_types_with_rhs! {SqlSubtract}
 mark_all_number_types_with_rhs! {SqlMultiply}
 mark_all_number_types_with_rhs! {SqlDivide}
+
+mark_sql_types_with_rhs! {SqlAdd => SqlInterval: (SqlInterval)}
+mark_sql_types_with_rhs! {SqlSubtract => SqlInterval: (SqlInterval)}
+mark_sql_types_with_rhs! {SqlMultiply => SqlInterval: (SqlF64)}
+mark_sql_types_with_rhs! {SqlDivide => SqlInterval: (SqlF64)}
+
+/// An sql type which a value of type `Rhs` can be subtracted from to find the
+/// difference between them, which is of a different type than the values
+/// themselves, like the interval between two timestamps.
+pub trait DifferenceableSqlType<Rhs> {
+    type OutputSqlType: SqlType<IsNull = TypedFalse>;
+}
+
+/// A marker trait which represents that a value of type `Self` can be divided
+/// by a value of type `Rhs` to find the remainder of the division.
+pub trait SqlMod<Rhs> {}
+
+mark_sql_types_with_rhs! { SqlMod =>
+    SqlI16: (SqlI16, Serial16),
+    SqlI32: (SqlI32, Serial32),
+    SqlI64: (SqlI64, Serial64),
+    SqlNumeric: (SqlNumeric),
+    Serial16: (Serial16, SqlI16),
+    Serial32: (Serial32, SqlI32),
+    Serial64: (Serial64, SqlI64)
+}
+
+/// A marker trait which represents that bitwise operations can be performed
+/// between a value of type `Self` and a value of type `Rhs`.
+pub trait SqlBitwise<Rhs> {}
+
+mark_sql_types_with_rhs! { SqlBitwise =>
+    SqlI16: (SqlI16, Serial16),
+    SqlI32: (SqlI32, Serial32),
+    SqlI64: (SqlI64, Serial64),
+    Serial16: (Serial16, SqlI16),
+    Serial32: (Serial32, SqlI32),
+    Serial64: (Serial64, SqlI64)
+}
+
+/// A marker trait which represents that the bits of a value of type `Self` can
+/// be shifted by a value of type `Rhs`.
+pub trait SqlShift<Rhs> {}
+
+mark_sql_types_with_rhs! { SqlShift =>
+    SqlI16: (SqlI32),
+    SqlI32: (SqlI32),
+    SqlI64: (SqlI32),
+    Serial16: (SqlI32),
+    Serial32: (SqlI32),
+    Serial64: (SqlI32)
+}
+
+/// A marker trait which represents that a non-nullable value of type `Self` can
+/// be explicitly cast to the non-nullable type `To`.
+///
+/// Every type can be cast to and from `text`, using the text representation of
+/// its values.
+pub trait SqlCastable<To> {}
+
+mark_sql_types_with_rhs! { SqlCastable =>
+    SqlBool: (SqlBool, SqlI32, SqlText),
+    SqlI16: (SqlI16, SqlI32, SqlI64, SqlF32, SqlF64, SqlNumeric, SqlText),
+    SqlI32: (SqlBool, SqlI16, SqlI32, SqlI64, SqlF32, SqlF64, SqlNumeric, SqlText),
+    SqlI64: (SqlI16, SqlI32, SqlI64, SqlF32, SqlF64, SqlNumeric, SqlText),
+    SqlF32: (SqlI16, SqlI32, SqlI64, SqlF32, SqlF64, SqlNumeric, SqlText),
+    SqlF64: (SqlI16, SqlI32, SqlI64, SqlF32, SqlF64, SqlNumeric, SqlText),
+    SqlNumeric: (SqlI16, SqlI32, SqlI64, SqlF32, SqlF64, SqlNumeric, SqlText),
+    SqlText: (SqlBool, SqlI16, SqlI32, SqlI64, SqlF32, SqlF64, SqlNumeric, SqlText, SqlInterval),
+    SqlInterval: (SqlInterval, SqlText)
+}
+
+mark_sql_types_with_rhs! { SqlCastable =>
+    SqlJson: (SqlJson, SqlJsonb, SqlText),
+    SqlJsonb: (SqlJson, SqlJsonb, SqlText),
+    SqlText: (SqlJson, SqlJsonb)
+}
+
+mark_sql_types_with_rhs! { SqlCastable =>
+    SqlBytea: (SqlBytea, SqlText),
+    SqlText: (SqlBytea)
+}
+
+/// An sql json type, whose fields and elements can be accessed.
+pub trait JsonSqlType: SqlType<IsNull = TypedFalse> {}
+
+mark_sql_types! {JsonSqlType => SqlJson, SqlJsonb}
+
+#[cfg(feature = "uuid")]
+mark_sql_types_with_rhs! { SqlCastable =>
+    SqlUuid: (SqlUuid, SqlText),
+    SqlText: (SqlUuid)
+}
+
+/// An sql date and time type, whose fields, like its year or its hour, can be
+/// extracted.
+pub trait TemporalSqlType {}
+
+mark_sql_types! {TemporalSqlType => SqlInterval}
+
+/// An sql timestamp type, which represents a point in time.
+pub trait TimestampSqlType: TemporalSqlType {}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+mod temporal;
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub use temporal::*;
@@ -0,0 +1,105 @@
+//! Sql date and time types, whose rust types are taken from the `chrono` crate
+//! if the `chrono` feature is enabled, and from the `time` crate otherwise.
+//!
+//! If both features are enabled, the types of both crates can be used as
+//! values of these sql types, but the values returned from the database are
+//! decoded as the types of the `chrono` crate.
+
+use super::{
+    ArrayElementSqlType, DifferenceableSqlType, IntoSqlType, OrderableSqlType, SqlAdd, SqlCastable,
+    SqlI32, SqlInterval, SqlSubtract, SqlText, SqlType, TemporalSqlType, TimestampSqlType,
+};
+use crate::util::TypedFalse;
+
+/// Defines an sql date and time type, which is mapped from the given `chrono`
+/// and `time` types.
+///
+/// Additional `chrono` types which should also be mapped to this sql type can be
+/// specified after the main one.
+macro_rules! define_temporal_sql_type {
+    {
+        $sql_type_name: ident, $sql_name: expr =>
+            chrono: $chrono_rust_type: ty $(| $other_chrono_rust_type: ty)*,
+            time: $time_rust_type: ty
+    } => {
+        #[cfg(feature = "chrono")]
+        define_sql_type! { $sql_type_name, $sql_name => $chrono_rust_type }
+
+        #[cfg(not(feature = "chrono"))]
+        define_sql_type! { $sql_type_name, $sql_name => $time_rust_type }
+
+        #[cfg(all(feature = "chrono", feature = "time"))]
+        impl IntoSqlType for $time_rust_type {
+            type SqlType = $sql_type_name;
+        }
+
+        $(
+            #[cfg(feature = "chrono")]
+            impl IntoSqlType for $other_chrono_rust_type {
+                type SqlType = $sql_type_name;
+            }
+        )*
+    };
+}
+
+define_temporal_sql_type! {
+    SqlTimestamp, "timestamp" =>
+        chrono: chrono::NaiveDateTime,
+        time: time::PrimitiveDateTime
+}
+define_temporal_sql_type! {
+    SqlTimestampTz, "timestamp with time zone" =>
+        chrono: chrono::DateTime<chrono::Utc>
+            | chrono::DateTime<chrono::FixedOffset>
+            | chrono::DateTime<chrono::Local>,
+        time: time::OffsetDateTime
+}
+define_temporal_sql_type! {
+    SqlDate, "date" =>
+        chrono: chrono::NaiveDate,
+        time: time::Date
+}
+define_temporal_sql_type! {
+    SqlTime, "time" =>
+        chrono: chrono::NaiveTime,
+        time: time::Time
+}
+
+mark_array_element_sql_types! {
+    SqlTimestamp: "timestamp[]",
+    SqlTimestampTz: "timestamp with time zone[]",
+    SqlDate: "date[]",
+    SqlTime: "time[]"
+}
+
+mark_sql_types! {OrderableSqlType => SqlTimestamp, SqlTimestampTz, SqlDate, SqlTime}
+mark_sql_types! {TemporalSqlType => SqlTimestamp, SqlTimestampTz, SqlDate, SqlTime}
+mark_sql_types! {TimestampSqlType => SqlTimestamp, SqlTimestampTz}
+
+mark_sql_types_with_rhs! { SqlAdd =>
+    SqlTimestamp: (SqlInterval),
+    SqlTimestampTz: (SqlInterval),
+    SqlDate: (SqlI32),
+    SqlTime: (SqlInterval)
+}
+
+mark_sql_types_with_rhs! { SqlSubtract =>
+    SqlTimestamp: (SqlInterval),
+    SqlTimestampTz: (SqlInterval),
+    SqlDate: (SqlI32),
+    SqlTime: (SqlInterval)
+}
+
+mark_sql_types_with_output_type! {DifferenceableSqlType<SqlTimestamp> => SqlTimestamp: SqlInterval}
+mark_sql_types_with_output_type! {DifferenceableSqlType<SqlTimestampTz> => SqlTimestampTz: SqlInterval}
+mark_sql_types_with_output_type! {DifferenceableSqlType<SqlDate> => SqlDate: SqlI32}
+mark_sql_types_with_output_type! {DifferenceableSqlType<SqlTime> => SqlTime: SqlInterval}
+
+mark_sql_types_with_rhs! { SqlCastable =>
+    SqlTimestamp: (SqlTimestamp, SqlTimestampTz, SqlDate, SqlTime, SqlText),
+    SqlTimestampTz: (SqlTimestamp, SqlTimestampTz, SqlDate, SqlTime, SqlText),
+    SqlDate: (SqlTimestamp, SqlTimestampTz, SqlDate, SqlText),
+    SqlTime: (SqlTime, SqlInterval, SqlText),
+    SqlInterval: (SqlTime),
+    SqlText: (SqlTimestamp, SqlTimestampTz, SqlDate, SqlTime)
+}
@@ -1,8 +1,11 @@
-use super::ParameterBinder;
+use super::{ParameterBinder, SelectableTables};
 use crate::Table;
 
 /// A set of updates to be performed on some row of a table.
-pub trait UpdateSet {
+///
+/// The `S` generic is the list of tables whose columns can be referenced by
+/// the new values of the updated columns.
+pub trait UpdateSet<S: SelectableTables> {
     /// The table which this update set operates on.
     type UpdateTable: Table;
 
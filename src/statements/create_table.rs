@@ -45,6 +45,8 @@ impl<T: Table> SqlStatement for CreateTableStatement<T> {
     }
 }
 
+impl_display_and_debug_for_sql_statement! { [T: Table] CreateTableStatement<T> }
+
 /// An sql create table if not exists statement.
 ///
 /// This statement only creates the table if a table with such a name doesn't
@@ -72,6 +74,8 @@ impl<T: Table> SqlStatement for CreateTableIfNotExistsStatement<T> {
     }
 }
 
+impl_display_and_debug_for_sql_statement! { [T: Table] CreateTableIfNotExistsStatement<T> }
+
 /// Generates the an sql string representing the content of this table,
 /// including columns, foreign keys and unique constraints.
 fn generate_create_table_content_sql_string(
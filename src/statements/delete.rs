@@ -1,8 +1,13 @@
 use std::marker::PhantomData;
 
-use super::SqlStatement;
+use super::{
+    AndFilterCombinator, FilterCombinator, OrFilterCombinator, SelectFrom, SqlStatement,
+};
 use crate::{
-    sql::{FieldsConsListItem, ParameterBinder, SelectedValues, SqlBool, SqlExpression},
+    sql::{
+        CombineSelectableTables, CombinedSelectableTables, FieldsConsListItem, ParameterBinder,
+        SelectableTables, SelectedValues, SqlBool, SqlExpression,
+    },
     util::{TypedBool, TypedConsListNil, TypedFalse, TypedTrue},
     Table,
 };
@@ -16,14 +21,23 @@ pub trait DeleteStatement: Sized {
     /// The table that this statement deletes from.
     type DeleteFrom: Table;
 
+    /// The tables whose columns can be referenced by this delete statement.
+    ///
+    /// For delete statements without a `USING` clause, this is just the table
+    /// that this statement deletes from.
+    type SelectableTables: SelectableTables;
+
     /// Does this delete statement have a `WHERE` clause?
     type HasWhereClause: TypedBool;
 
     /// Does this delete statement have a `RETURNING` clause?
     type HasReturningClause: TypedBool;
 
-    /// Writes the `WHERE` clause of this delete statement.
-    fn write_where_clause<'s, 'a>(
+    /// Writes the `USING` clause of this delete statement.
+    fn write_using_clause(&self, f: &mut String) -> std::fmt::Result;
+
+    /// Writes the condition of the `WHERE` clause of this delete statement.
+    fn write_where_condition<'s, 'a>(
         &'s self,
         f: &mut String,
         parameter_binder: &mut ParameterBinder<'a>,
@@ -56,7 +70,11 @@ pub trait DeleteStatement: Sized {
             "DELETE  FROM {}",
             <Self::DeleteFrom as Table>::TABLE_NAME
         )?;
-        self.write_where_clause(f, parameter_binder)?;
+        self.write_using_clause(f)?;
+        if Self::HasWhereClause::VALUE {
+            write!(f, " WHERE ")?;
+            self.write_where_condition(f, parameter_binder)?;
+        }
         self.write_returning_clause(f, parameter_binder)
     }
 }
@@ -82,6 +100,11 @@ macro_rules! impl_sql_statement_for_delete_statement {
 
 /// An sql delete statement which deletes all records from the table.
 ///
+/// This statement can't be executed as is, to prevent accidentally deleting all
+/// records from the table. It must first either be filtered using the
+/// [`FilterDeleteStatement::filter`] function, or explicitly marked as deleting
+/// all records using the [`DeleteStatementAllRows::all_rows`] function.
+///
 /// This statement can be created by calling the [`TableMarker::delete`]
 /// function.
 ///
@@ -93,6 +116,15 @@ impl<T: Table> EmptyDeleteStatement<T> {
     pub fn new() -> Self {
         Self(PhantomData)
     }
+
+    /// Adds a `USING` clause to this delete statement, which allows its
+    /// condition to reference columns of other tables.
+    pub fn using<U: SelectFrom>(self, _using: U) -> EmptyDeleteUsingStatement<T, U>
+    where
+        T: CombineSelectableTables<U::SelectableTables>,
+    {
+        EmptyDeleteUsingStatement(PhantomData, PhantomData)
+    }
 }
 
 impl<T: Table> DeleteStatement for EmptyDeleteStatement<T> {
@@ -100,8 +132,13 @@ impl<T: Table> DeleteStatement for EmptyDeleteStatement<T> {
     type HasReturningClause = TypedFalse;
     type HasWhereClause = TypedFalse;
     type OutputFields = TypedConsListNil;
+    type SelectableTables = T;
 
-    fn write_where_clause<'s, 'a>(
+    fn write_using_clause(&self, _f: &mut String) -> std::fmt::Result {
+        Ok(())
+    }
+
+    fn write_where_condition<'s, 'a>(
         &'s self,
         _f: &mut String,
         _parameter_binder: &mut ParameterBinder<'a>,
@@ -124,33 +161,90 @@ impl<T: Table> DeleteStatement for EmptyDeleteStatement<T> {
     }
 }
 
-impl<T: Table> SqlStatement for EmptyDeleteStatement<T> {
-    impl_sql_statement_for_delete_statement! {}
+/// An sql delete statement with a `USING` clause which deletes all records from
+/// the table.
+///
+/// Just like [`EmptyDeleteStatement`], this statement can't be executed before
+/// it is filtered or explicitly marked as deleting all records.
+///
+/// This statement can be created by calling the [`EmptyDeleteStatement::using`]
+/// function.
+pub struct EmptyDeleteUsingStatement<T: Table, U: SelectFrom>(PhantomData<T>, PhantomData<U>)
+where
+    T: CombineSelectableTables<U::SelectableTables>;
+impl<T: Table, U: SelectFrom> DeleteStatement for EmptyDeleteUsingStatement<T, U>
+where
+    T: CombineSelectableTables<U::SelectableTables>,
+{
+    type DeleteFrom = T;
+    type HasReturningClause = TypedFalse;
+    type HasWhereClause = TypedFalse;
+    type OutputFields = TypedConsListNil;
+    type SelectableTables = CombinedSelectableTables<T, U::SelectableTables>;
+
+    fn write_using_clause(&self, f: &mut String) -> std::fmt::Result {
+        use std::fmt::Write;
+
+        write!(f, " USING ")?;
+        U::write_sql_from_string(f)
+    }
+
+    fn write_where_condition<'s, 'a>(
+        &'s self,
+        _f: &mut String,
+        _parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        Ok(())
+    }
+
+    fn write_returning_clause<'s, 'a>(
+        &'s self,
+        _f: &mut String,
+        _parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        Ok(())
+    }
 }
 
-/// A wrapper around an sql delete statement which adds a `WHERE` clause to it.
+/// A wrapper around an sql delete statement which adds a condition to its
+/// `WHERE` clause, combining it with the existing condition if there is one.
 ///
 /// This wrapper shouldn't be used directly, you should instead use the
-/// [`FilterDeleteStatement::filter`] function.
+/// [`FilterDeleteStatement::filter`] or
+/// [`OrFilterDeleteStatement::or_filter`] functions.
 pub struct DeleteWithWhereClause<
-    S: DeleteStatement<HasWhereClause = TypedFalse>,
-    C: SqlExpression<S::DeleteFrom, SqlType = SqlBool>,
+    S: DeleteStatement,
+    C: SqlExpression<S::SelectableTables, SqlType = SqlBool>,
+    O: FilterCombinator,
 > {
     statement: S,
     condition: C,
+    phantom: PhantomData<O>,
 }
 
 impl<
-    S: DeleteStatement<HasWhereClause = TypedFalse>,
-    C: SqlExpression<S::DeleteFrom, SqlType = SqlBool>,
-> DeleteStatement for DeleteWithWhereClause<S, C>
+    S: DeleteStatement,
+    C: SqlExpression<S::SelectableTables, SqlType = SqlBool>,
+    O: FilterCombinator,
+> DeleteStatement for DeleteWithWhereClause<S, C, O>
 {
     type DeleteFrom = S::DeleteFrom;
     type HasReturningClause = S::HasReturningClause;
     type HasWhereClause = TypedTrue;
     type OutputFields = S::OutputFields;
+    type SelectableTables = S::SelectableTables;
 
-    fn write_where_clause<'s, 'a>(
+    fn write_using_clause(&self, f: &mut String) -> std::fmt::Result {
+        self.statement.write_using_clause(f)
+    }
+
+    fn write_where_condition<'s, 'a>(
         &'s self,
         f: &mut String,
         parameter_binder: &mut ParameterBinder<'a>,
@@ -160,8 +254,15 @@ impl<
     {
         use std::fmt::Write;
 
-        write!(f, " WHERE ")?;
-        self.condition.write_sql_string(f, parameter_binder)
+        if S::HasWhereClause::VALUE {
+            write!(f, "(")?;
+            self.statement.write_where_condition(f, parameter_binder)?;
+            write!(f, ") {} (", O::OPERATOR_STR)?;
+            self.condition.write_sql_string(f, parameter_binder)?;
+            write!(f, ")")
+        } else {
+            self.condition.write_sql_string(f, parameter_binder)
+        }
     }
 
     fn write_returning_clause<'s, 'a>(
@@ -177,30 +278,140 @@ impl<
 }
 
 impl<
-    S: DeleteStatement<HasWhereClause = TypedFalse> + 'static,
-    C: SqlExpression<S::DeleteFrom, SqlType = SqlBool> + 'static,
-> SqlStatement for DeleteWithWhereClause<S, C>
+    S: DeleteStatement + 'static,
+    C: SqlExpression<S::SelectableTables, SqlType = SqlBool> + 'static,
+    O: FilterCombinator + 'static,
+> SqlStatement for DeleteWithWhereClause<S, C, O>
 {
     impl_sql_statement_for_delete_statement! {}
 }
 
+impl_display_and_debug_for_sql_statement! {
+    [
+        S: DeleteStatement,
+        C: SqlExpression<S::SelectableTables, SqlType = SqlBool>,
+        O: FilterCombinator,
+    ]
+    DeleteWithWhereClause<S, C, O>
+}
+
 /// A trait which allows filtering a delete statement so that it only deletes
 /// records matching some condition.
-pub trait FilterDeleteStatement: DeleteStatement<HasWhereClause = TypedFalse> {
+pub trait FilterDeleteStatement: DeleteStatement {
     /// Filters this delete statement, so that it only deletes records which
     /// match the given condition.
-    fn filter<C: SqlExpression<Self::DeleteFrom, SqlType = SqlBool>>(
+    ///
+    /// If this statement is already filtered, the given condition is combined
+    /// with the existing one using the `AND` operator.
+    fn filter<C: SqlExpression<Self::SelectableTables, SqlType = SqlBool>>(
         self,
         condition: C,
-    ) -> DeleteWithWhereClause<Self, C> {
+    ) -> DeleteWithWhereClause<Self, C, AndFilterCombinator> {
         DeleteWithWhereClause {
             statement: self,
             condition,
+            phantom: PhantomData,
         }
     }
 }
 
-impl<T: DeleteStatement<HasWhereClause = TypedFalse>> FilterDeleteStatement for T {}
+impl<T: DeleteStatement> FilterDeleteStatement for T {}
+
+/// A trait which allows extending the filter of an already filtered delete
+/// statement so that it also deletes records matching some other condition.
+pub trait OrFilterDeleteStatement: DeleteStatement<HasWhereClause = TypedTrue> {
+    /// Extends the filter of this delete statement, so that it also deletes
+    /// records which match the given condition, by combining it with the
+    /// existing condition using the `OR` operator.
+    fn or_filter<C: SqlExpression<Self::SelectableTables, SqlType = SqlBool>>(
+        self,
+        condition: C,
+    ) -> DeleteWithWhereClause<Self, C, OrFilterCombinator> {
+        DeleteWithWhereClause {
+            statement: self,
+            condition,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: DeleteStatement<HasWhereClause = TypedTrue>> OrFilterDeleteStatement for T {}
+
+/// A wrapper around an sql delete statement which explicitly marks it as
+/// deleting all records from the table, which allows executing it without
+/// filtering it.
+///
+/// This wrapper shouldn't be used directly, you should instead use the
+/// [`DeleteStatementAllRows::all_rows`] function.
+pub struct DeleteWithAllRows<S: DeleteStatement<HasWhereClause = TypedFalse>> {
+    statement: S,
+}
+
+impl<S: DeleteStatement<HasWhereClause = TypedFalse>> DeleteStatement for DeleteWithAllRows<S> {
+    type DeleteFrom = S::DeleteFrom;
+    type HasReturningClause = S::HasReturningClause;
+    type HasWhereClause = TypedTrue;
+    type OutputFields = S::OutputFields;
+    type SelectableTables = S::SelectableTables;
+
+    fn write_using_clause(&self, f: &mut String) -> std::fmt::Result {
+        self.statement.write_using_clause(f)
+    }
+
+    fn write_where_condition<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        _parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        use std::fmt::Write;
+
+        // the condition of a statement which affects all rows is always true, so
+        // that it can still be combined with other conditions.
+        write!(f, "TRUE")
+    }
+
+    fn write_returning_clause<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        self.statement.write_returning_clause(f, parameter_binder)
+    }
+}
+
+impl<S: DeleteStatement<HasWhereClause = TypedFalse> + 'static> SqlStatement
+    for DeleteWithAllRows<S>
+{
+    impl_sql_statement_for_delete_statement! {}
+}
+
+impl_display_and_debug_for_sql_statement! {
+    [
+        S: DeleteStatement<HasWhereClause = TypedFalse>,
+    ]
+    DeleteWithAllRows<S>
+}
+
+/// A trait which allows explicitly marking a delete statement as deleting all
+/// records from the table.
+pub trait DeleteStatementAllRows: DeleteStatement<HasWhereClause = TypedFalse> {
+    /// Marks this delete statement as deleting all records from the table.
+    ///
+    /// Delete statements which aren't filtered can't be executed unless
+    /// this function is called, to prevent accidentally deleting all records
+    /// from the table.
+    fn all_rows(self) -> DeleteWithAllRows<Self> {
+        DeleteWithAllRows { statement: self }
+    }
+}
+
+impl<T: DeleteStatement<HasWhereClause = TypedFalse>> DeleteStatementAllRows for T {}
 
 /// A wrapper around an sql delete statement which adds a `RETURNING` clause to
 /// it.
@@ -209,21 +420,26 @@ impl<T: DeleteStatement<HasWhereClause = TypedFalse>> FilterDeleteStatement for
 /// [`DeleteStatementReturning::returning`] function.
 pub struct DeleteWithReturningClause<
     S: DeleteStatement<HasReturningClause = TypedFalse>,
-    R: SelectedValues<S::DeleteFrom>,
+    R: SelectedValues<S::SelectableTables>,
 > {
     statement: S,
     returning: R,
 }
 
-impl<S: DeleteStatement<HasReturningClause = TypedFalse>, R: SelectedValues<S::DeleteFrom>>
+impl<S: DeleteStatement<HasReturningClause = TypedFalse>, R: SelectedValues<S::SelectableTables>>
     DeleteStatement for DeleteWithReturningClause<S, R>
 {
     type DeleteFrom = S::DeleteFrom;
     type HasReturningClause = TypedTrue;
     type HasWhereClause = S::HasWhereClause;
     type OutputFields = R::Fields;
+    type SelectableTables = S::SelectableTables;
 
-    fn write_where_clause<'s, 'a>(
+    fn write_using_clause(&self, f: &mut String) -> std::fmt::Result {
+        self.statement.write_using_clause(f)
+    }
+
+    fn write_where_condition<'s, 'a>(
         &'s self,
         f: &mut String,
         parameter_binder: &mut ParameterBinder<'a>,
@@ -231,7 +447,7 @@ impl<S: DeleteStatement<HasReturningClause = TypedFalse>, R: SelectedValues<S::D
     where
         's: 'a,
     {
-        self.statement.write_where_clause(f, parameter_binder)
+        self.statement.write_where_condition(f, parameter_binder)
     }
 
     fn write_returning_clause<'s, 'a>(
@@ -250,13 +466,21 @@ impl<S: DeleteStatement<HasReturningClause = TypedFalse>, R: SelectedValues<S::D
 }
 
 impl<
-    S: DeleteStatement<HasReturningClause = TypedFalse> + 'static,
-    R: SelectedValues<S::DeleteFrom> + 'static,
+    S: DeleteStatement<HasReturningClause = TypedFalse, HasWhereClause = TypedTrue> + 'static,
+    R: SelectedValues<S::SelectableTables> + 'static,
 > SqlStatement for DeleteWithReturningClause<S, R>
 {
     impl_sql_statement_for_delete_statement! {}
 }
 
+impl_display_and_debug_for_sql_statement! {
+    [
+        S: DeleteStatement<HasReturningClause = TypedFalse>,
+        R: SelectedValues<S::SelectableTables>,
+    ]
+    DeleteWithReturningClause<S, R>
+}
+
 /// A trait which allows returning some values from the records deleted by some
 /// delete statement.
 pub trait DeleteStatementReturning: DeleteStatement<HasReturningClause = TypedFalse> {
@@ -265,7 +489,7 @@ pub trait DeleteStatementReturning: DeleteStatement<HasReturningClause = TypedFa
     /// [`returning!`] macro.
     ///
     /// [`returning!`]: crate::returning
-    fn returning<R: SelectedValues<Self::DeleteFrom>>(
+    fn returning<R: SelectedValues<Self::SelectableTables>>(
         self,
         returning: R,
     ) -> DeleteWithReturningClause<Self, R> {
@@ -39,6 +39,8 @@ impl<T: Table> SqlStatement for DropTableStatement<T> {
     }
 }
 
+impl_display_and_debug_for_sql_statement! { [T: Table] DropTableStatement<T> }
+
 /// An sql drop table if exists statement.
 ///
 /// This statement doesn't return an error in case the table does not exist.
@@ -60,3 +62,5 @@ impl<T: Table> SqlStatement for DropTableIfExistsStatement<T> {
         write!(f, "DROP TABLE IF EXISTS {}", T::TABLE_NAME)
     }
 }
+
+impl_display_and_debug_for_sql_statement! { [T: Table] DropTableIfExistsStatement<T> }
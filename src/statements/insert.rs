@@ -60,9 +60,8 @@ pub trait InsertStatement: Sized {
             <<Self::Insertable as Insertable>::Table as Table>::TABLE_NAME,
         )?;
         self.get_insertable().write_value_names(f)?;
-        write!(f, ") VALUES(")?;
-        self.get_insertable().write_values(f, parameter_binder)?;
-        write!(f, ")")?;
+        write!(f, ") ")?;
+        self.get_insertable().write_values_clause(f, parameter_binder)?;
         self.write_on_conflict_clause(f, parameter_binder)?;
         self.write_returning_clause(f, parameter_binder)?;
 
@@ -138,6 +137,8 @@ impl<I: Insertable> SqlStatement for EmptyInsertStatement<I> {
     impl_sql_statement_for_insert_statement! {}
 }
 
+impl_display_and_debug_for_sql_statement! { [I: Insertable] EmptyInsertStatement<I> }
+
 /// A wrapper around an sql insert statement which adds a `RETURNING` clause to
 /// it.
 ///
@@ -199,6 +200,14 @@ impl<
     impl_sql_statement_for_insert_statement! {}
 }
 
+impl_display_and_debug_for_sql_statement! {
+    [
+        S: InsertStatement<HasReturningClause = TypedFalse>,
+        R: SelectedValues<<S::Insertable as Insertable>::Table>,
+    ]
+    InsertWithReturningClause<S, R>
+}
+
 /// A trait which allows returning some values from the records inserted by some
 /// insert statement.
 pub trait InsertStatementReturning: InsertStatement<HasReturningClause = TypedFalse> {
@@ -245,7 +254,7 @@ impl<
     /// applied in case of a conflict, use the [`update_set!`] macro.
     ///
     /// [`update_set!`]: gorm_macros::update_set
-    pub fn do_update<U: UpdateSet<UpdateTable = <S::Insertable as Insertable>::Table>>(
+    pub fn do_update<U: UpdateSet<<S::Insertable as Insertable>::Table, UpdateTable = <S::Insertable as Insertable>::Table>>(
         self,
         update_set: U,
     ) -> InsertWithOnConflictClause<S, C, U> {
@@ -298,7 +307,7 @@ impl<T: InsertStatement<HasOnConflictClause = TypedFalse>> InsertStatementOnConf
 pub struct InsertWithOnConflictClause<
     S: InsertStatement<HasOnConflictClause = TypedFalse>,
     C: UniqueConstraint<Table = <S::Insertable as Insertable>::Table>,
-    U: UpdateSet<UpdateTable = <S::Insertable as Insertable>::Table>,
+    U: UpdateSet<<S::Insertable as Insertable>::Table, UpdateTable = <S::Insertable as Insertable>::Table>,
 > {
     statement: S,
     _constraint: C,
@@ -308,7 +317,7 @@ pub struct InsertWithOnConflictClause<
 impl<
     S: InsertStatement<HasOnConflictClause = TypedFalse>,
     C: UniqueConstraint<Table = <S::Insertable as Insertable>::Table>,
-    U: UpdateSet<UpdateTable = <S::Insertable as Insertable>::Table>,
+    U: UpdateSet<<S::Insertable as Insertable>::Table, UpdateTable = <S::Insertable as Insertable>::Table>,
 > InsertStatement for InsertWithOnConflictClause<S, C, U>
 {
     type HasOnConflictClause = TypedTrue;
@@ -353,12 +362,21 @@ impl<
 impl<
     S: InsertStatement<HasOnConflictClause = TypedFalse>,
     C: UniqueConstraint<Table = <S::Insertable as Insertable>::Table>,
-    U: UpdateSet<UpdateTable = <S::Insertable as Insertable>::Table>,
+    U: UpdateSet<<S::Insertable as Insertable>::Table, UpdateTable = <S::Insertable as Insertable>::Table>,
 > SqlStatement for InsertWithOnConflictClause<S, C, U>
 {
     impl_sql_statement_for_insert_statement! {}
 }
 
+impl_display_and_debug_for_sql_statement! {
+    [
+        S: InsertStatement<HasOnConflictClause = TypedFalse>,
+        C: UniqueConstraint<Table = <S::Insertable as Insertable>::Table>,
+        U: UpdateSet<<S::Insertable as Insertable>::Table, UpdateTable = <S::Insertable as Insertable>::Table>,
+    ]
+    InsertWithOnConflictClause<S, C, U>
+}
+
 /// A wrapper around an sql insert statement which adds an `ON CONFLICT DO
 /// NOTHING` clause to it.
 ///
@@ -411,3 +429,10 @@ impl<S: InsertStatement<HasOnConflictClause = TypedFalse>> SqlStatement
 {
     impl_sql_statement_for_insert_statement! {}
 }
+
+impl_display_and_debug_for_sql_statement! {
+    [
+        S: InsertStatement<HasOnConflictClause = TypedFalse>,
+    ]
+    InsertWithOnConflictDoNothingClause<S>
+}
@@ -1,5 +1,33 @@
 //! Implementation of different sql statements.
 
+/// Implements the [`std::fmt::Display`] and [`std::fmt::Debug`] traits for an
+/// sql statement type, formatting it as its sql string with all of its
+/// parameters inlined.
+///
+/// The generics of the type along with their bounds should be provided inside
+/// square brackets before the type itself.
+macro_rules! impl_display_and_debug_for_sql_statement {
+    ([$($generics: tt)*] $statement_type: ty) => {
+        impl<$($generics)*> std::fmt::Display for $statement_type
+        where
+            Self: crate::statements::SqlStatement,
+        {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&crate::statements::SqlStatement::to_debug_sql(self))
+            }
+        }
+
+        impl<$($generics)*> std::fmt::Debug for $statement_type
+        where
+            Self: crate::statements::SqlStatement,
+        {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Display::fmt(self, f)
+            }
+        }
+    };
+}
+
 mod create_table;
 mod delete;
 mod drop_table;
@@ -18,7 +46,7 @@ pub use update::*;
 
 use crate::{
     error::*,
-    execution::{ExecuteResult, SqlStatementExecutor},
+    execution::{ExecuteResult, QueryPlan, SqlStatementExecutor},
     sql::{
         FieldNameCharsConsListItem, FieldsConsListCons, FieldsConsListItem, FromQueryResult,
         ParameterBinder,
@@ -51,6 +79,20 @@ pub trait SqlStatement: Sized {
             .unwrap();
         (query_string, parameter_binder)
     }
+
+    /// Builds the statement into an sql string in which all bound parameters
+    /// are inlined as sql literals containing their values.
+    ///
+    /// This is useful for logging and debugging, but the resulting string
+    /// should not be executed on the database, parameters should always be
+    /// bound using [`SqlStatement::build`] instead.
+    fn to_debug_sql(&self) -> String {
+        let mut parameter_binder = ParameterBinder::new_inlining();
+        let mut query_string = String::new();
+        self.write_sql_string(&mut query_string, &mut parameter_binder)
+            .unwrap();
+        query_string
+    }
 }
 
 /// An sql statement which can be executed on the database.
@@ -67,6 +109,31 @@ pub trait ExecuteSqlStatment: SqlStatement {
 #[async_trait]
 impl<S: SqlStatement> ExecuteSqlStatment for S {}
 
+/// An sql statement which can be explained by the database to find out how it
+/// will be executed.
+///
+/// This is implemented for all sql statements.
+#[async_trait]
+pub trait ExplainSqlStatement: SqlStatement {
+    /// Explains the sql statement on the given executor, without executing
+    /// it.
+    async fn explain(self, on: &impl SqlStatementExecutor) -> Result<QueryPlan> {
+        on.explain(self).await
+    }
+
+    /// Explains the sql statement on the given executor, executing it in
+    /// order to collect the actual timings and row counts of the plan.
+    ///
+    /// Please note that any modifications performed by the statement will be
+    /// applied.
+    async fn explain_analyze(self, on: &impl SqlStatementExecutor) -> Result<QueryPlan> {
+        on.explain_analyze(self).await
+    }
+}
+
+#[async_trait]
+impl<S: SqlStatement> ExplainSqlStatement for S {}
+
 /// An sql statement which is a query that has an output which can be loaded and
 /// parsed.
 #[async_trait]
@@ -143,3 +210,22 @@ impl<
 > LoadSingleColumnSqlStatment<FieldName, FieldType> for S
 {
 }
+
+/// The logical operator used to combine a new condition with the existing
+/// condition of the `WHERE` clause of a statement.
+pub trait FilterCombinator {
+    /// The sql operator used to combine the conditions.
+    const OPERATOR_STR: &'static str;
+}
+
+/// Combines the conditions using the `AND` operator.
+pub struct AndFilterCombinator;
+impl FilterCombinator for AndFilterCombinator {
+    const OPERATOR_STR: &'static str = "AND";
+}
+
+/// Combines the conditions using the `OR` operator.
+pub struct OrFilterCombinator;
+impl FilterCombinator for OrFilterCombinator {
+    const OPERATOR_STR: &'static str = "OR";
+}
@@ -1,6 +1,6 @@
 use std::{fmt::Write, marker::PhantomData};
 
-use super::SqlStatement;
+use super::{AndFilterCombinator, FilterCombinator, OrFilterCombinator, SqlStatement};
 use crate::{
     sql::{
         Column, ColumnIsForeignKey, CombineSelectableTables, CombinedSelectableTables,
@@ -64,8 +64,8 @@ pub trait SelectStatement: SqlStatement {
     where
         's: 'a;
 
-    /// Writes the `WHERE` clause of this select statement.
-    fn write_where_clause<'s, 'a>(
+    /// Writes the condition of the `WHERE` clause of this select statement.
+    fn write_where_condition<'s, 'a>(
         &'s self,
         f: &mut String,
         parameter_binder: &mut ParameterBinder<'a>,
@@ -104,7 +104,10 @@ pub trait SelectStatement: SqlStatement {
         self.write_selected_values(f, parameter_binder)?;
         write!(f, " FROM ")?;
         <Self as SelectStatement>::SelectFrom::write_sql_from_string(f)?;
-        self.write_where_clause(f, parameter_binder)?;
+        if Self::HasWhereClause::VALUE {
+            write!(f, " WHERE ")?;
+            self.write_where_condition(f, parameter_binder)?;
+        }
         self.write_group_by_clause(f, parameter_binder)?;
         self.write_order_by_clause(f, parameter_binder)
     }
@@ -159,7 +162,7 @@ impl<S: SelectFrom + 'static> SelectStatement for EmptySelectStatement<S> {
         write!(f, "*")
     }
 
-    fn write_where_clause<'s, 'a>(
+    fn write_where_condition<'s, 'a>(
         &'s self,
         _f: &mut String,
         _parameter_binder: &mut ParameterBinder<'a>,
@@ -197,6 +200,8 @@ impl<S: SelectFrom + 'static> SqlStatement for EmptySelectStatement<S> {
     impl_sql_statement_for_select_statement! {}
 }
 
+impl_display_and_debug_for_sql_statement! { [S: SelectFrom] EmptySelectStatement<S> }
+
 /// A wrapper around an sql select statement which selects custom values from
 /// it. for it.
 ///
@@ -237,7 +242,7 @@ impl<
         self.values.write_sql_string(f, parameter_binder)
     }
 
-    fn write_where_clause<'s, 'a>(
+    fn write_where_condition<'s, 'a>(
         &'s self,
         f: &mut String,
         parameter_binder: &mut ParameterBinder<'a>,
@@ -245,7 +250,7 @@ impl<
     where
         's: 'a,
     {
-        self.statement.write_where_clause(f, parameter_binder)
+        self.statement.write_where_condition(f, parameter_binder)
     }
 
     fn write_group_by_clause<'s, 'a>(
@@ -280,6 +285,15 @@ impl<
     impl_sql_statement_for_select_statement! {}
 }
 
+impl_display_and_debug_for_sql_statement! {
+    [
+        S: SelectFrom,
+        T: SelectStatement<HasSelectedValues = TypedFalse>,
+        V: SelectedValues<S::SelectableTables>,
+    ]
+    WithSelectedValues<S, T, V>
+}
+
 /// A trait which allows selecting custom values from an sql select statement.
 pub trait SelectValues: SelectStatement<HasSelectedValues = TypedFalse> {
     /// Selects custom values from this select statement. To provide a list of
@@ -299,24 +313,27 @@ pub trait SelectValues: SelectStatement<HasSelectedValues = TypedFalse> {
 }
 impl<T: SelectStatement<HasSelectedValues = TypedFalse>> SelectValues for T {}
 
-/// A wrapper around an sql select statement which adds a `WHERE` clause to it.
+/// A wrapper around an sql select statement which adds a condition to its
+/// `WHERE` clause, combining it with the existing condition if there is one.
 ///
 /// This wrapper shouldn't be used directly, you should instead use the
-/// [`Filter::filter`] function.
+/// [`Filter::filter`] or [`OrFilter::or_filter`] functions.
 pub struct WithWhereClause<
     S: SelectFrom,
-    T: SelectStatement<HasWhereClause = TypedFalse>,
+    T: SelectStatement,
     C: SqlExpression<S::SelectableTables, SqlType = SqlBool>,
+    O: FilterCombinator,
 > {
     statement: T,
     condition: C,
-    phantom: PhantomData<S>,
+    phantom: PhantomData<(S, O)>,
 }
 impl<
     S: SelectFrom + 'static,
-    T: SelectStatement<HasWhereClause = TypedFalse>,
+    T: SelectStatement,
     C: SqlExpression<S::SelectableTables, SqlType = SqlBool> + 'static,
-> SelectStatement for WithWhereClause<S, T, C>
+    O: FilterCombinator + 'static,
+> SelectStatement for WithWhereClause<S, T, C, O>
 {
     type HasGroupByClause = T::HasGroupByClause;
     type HasOrderByClause = T::HasOrderByClause;
@@ -326,7 +343,7 @@ impl<
     type SelectFrom = S;
     type SelectedValues = T::SelectedValues;
 
-    fn write_where_clause<'s, 'a>(
+    fn write_where_condition<'s, 'a>(
         &'s self,
         f: &mut String,
         parameter_binder: &mut ParameterBinder<'a>,
@@ -334,8 +351,15 @@ impl<
     where
         's: 'a,
     {
-        write!(f, " WHERE ")?;
-        self.condition.write_sql_string(f, parameter_binder)
+        if T::HasWhereClause::VALUE {
+            write!(f, "(")?;
+            self.statement.write_where_condition(f, parameter_binder)?;
+            write!(f, ") {} (", O::OPERATOR_STR)?;
+            self.condition.write_sql_string(f, parameter_binder)?;
+            write!(f, ")")
+        } else {
+            self.condition.write_sql_string(f, parameter_binder)
+        }
     }
 
     fn write_selected_values<'s, 'a>(
@@ -374,24 +398,38 @@ impl<
 
 impl<
     S: SelectFrom + 'static,
-    T: SelectStatement<HasWhereClause = TypedFalse>,
+    T: SelectStatement,
     C: SqlExpression<S::SelectableTables, SqlType = SqlBool> + 'static,
-> SqlStatement for WithWhereClause<S, T, C>
+    O: FilterCombinator + 'static,
+> SqlStatement for WithWhereClause<S, T, C, O>
 {
     impl_sql_statement_for_select_statement! {}
 }
 
+impl_display_and_debug_for_sql_statement! {
+    [
+        S: SelectFrom,
+        T: SelectStatement,
+        C: SqlExpression<S::SelectableTables, SqlType = SqlBool>,
+        O: FilterCombinator,
+    ]
+    WithWhereClause<S, T, C, O>
+}
+
 /// A trait which allows filtering a select statement so that it only selects
 /// records matching some condition.
-pub trait Filter: SelectStatement<HasWhereClause = TypedFalse> {
+pub trait Filter: SelectStatement {
     /// Filters this select statement, so that it only returns records which
     /// match the given condition.
+    ///
+    /// If this statement is already filtered, the given condition is combined
+    /// with the existing one using the `AND` operator.
     fn filter<
         C: SqlExpression<<Self::SelectFrom as SelectFrom>::SelectableTables, SqlType = SqlBool>,
     >(
         self,
         condition: C,
-    ) -> WithWhereClause<Self::SelectFrom, Self, C> {
+    ) -> WithWhereClause<Self::SelectFrom, Self, C, AndFilterCombinator> {
         WithWhereClause {
             statement: self,
             condition,
@@ -399,7 +437,28 @@ pub trait Filter: SelectStatement<HasWhereClause = TypedFalse> {
         }
     }
 }
-impl<T: SelectStatement<HasWhereClause = TypedFalse>> Filter for T {}
+impl<T: SelectStatement> Filter for T {}
+
+/// A trait which allows extending the filter of an already filtered select
+/// statement so that it also selects records matching some other condition.
+pub trait OrFilter: SelectStatement<HasWhereClause = TypedTrue> {
+    /// Extends the filter of this select statement, so that it also returns
+    /// records which match the given condition, by combining it with the
+    /// existing condition using the `OR` operator.
+    fn or_filter<
+        C: SqlExpression<<Self::SelectFrom as SelectFrom>::SelectableTables, SqlType = SqlBool>,
+    >(
+        self,
+        condition: C,
+    ) -> WithWhereClause<Self::SelectFrom, Self, C, OrFilterCombinator> {
+        WithWhereClause {
+            statement: self,
+            condition,
+            phantom: PhantomData,
+        }
+    }
+}
+impl<T: SelectStatement<HasWhereClause = TypedTrue>> OrFilter for T {}
 
 /// A wrapper around an sql select statement which adds a `GROUP BY` clause to
 /// it.
@@ -452,7 +511,7 @@ impl<
         self.statement.write_selected_values(f, parameter_binder)
     }
 
-    fn write_where_clause<'s, 'a>(
+    fn write_where_condition<'s, 'a>(
         &'s self,
         f: &mut String,
         parameter_binder: &mut ParameterBinder<'a>,
@@ -460,7 +519,7 @@ impl<
     where
         's: 'a,
     {
-        self.statement.write_where_clause(f, parameter_binder)
+        self.statement.write_where_condition(f, parameter_binder)
     }
 
     fn write_order_by_clause<'s, 'a>(
@@ -484,6 +543,15 @@ impl<
     impl_sql_statement_for_select_statement! {}
 }
 
+impl_display_and_debug_for_sql_statement! {
+    [
+        S: SelectFrom,
+        T: SelectStatement<HasGroupByClause = TypedFalse>,
+        G: SqlExpression<S::SelectableTables>,
+    ]
+    WithGroupByClause<S, T, G>
+}
+
 /// A trait which allows grouping the results of a select statement which uses
 /// aggregate functions by some expression.
 pub trait GroupBy: SelectStatement<HasGroupByClause = TypedFalse> {
@@ -574,7 +642,7 @@ impl<
         self.statement.write_selected_values(f, parameter_binder)
     }
 
-    fn write_where_clause<'s, 'a>(
+    fn write_where_condition<'s, 'a>(
         &'s self,
         f: &mut String,
         parameter_binder: &mut ParameterBinder<'a>,
@@ -582,7 +650,7 @@ impl<
     where
         's: 'a,
     {
-        self.statement.write_where_clause(f, parameter_binder)
+        self.statement.write_where_condition(f, parameter_binder)
     }
 
     fn write_order_by_clause<'s, 'a>(
@@ -609,6 +677,16 @@ impl<
     impl_sql_statement_for_select_statement! {}
 }
 
+impl_display_and_debug_for_sql_statement! {
+    [
+        S: SelectFrom,
+        T: SelectStatement<HasOrderByClause = TypedFalse>,
+        B: SqlExpression<S::SelectableTables>,
+        O: Ordering,
+    ]
+    WithOrderByClause<S, T, B, O>
+}
+
 /// A trait which allows ordering the results of a select statement by some
 /// expression in an ascending or descending order.
 pub trait OrderBy: SelectStatement<HasOrderByClause = TypedFalse> {
@@ -705,7 +783,7 @@ impl<
         self.statement.write_selected_values(f, parameter_binder)
     }
 
-    fn write_where_clause<'s, 'a>(
+    fn write_where_condition<'s, 'a>(
         &'s self,
         f: &mut String,
         parameter_binder: &mut ParameterBinder<'a>,
@@ -713,7 +791,7 @@ impl<
     where
         's: 'a,
     {
-        self.statement.write_where_clause(f, parameter_binder)
+        self.statement.write_where_condition(f, parameter_binder)
     }
 
     fn write_order_by_clause<'s, 'a>(
@@ -738,6 +816,16 @@ impl<
     impl_sql_statement_for_select_statement! {}
 }
 
+impl_display_and_debug_for_sql_statement! {
+    [
+        S: SelectFrom,
+        T: SelectStatement<HasOrderByClause = TypedFalse>,
+        B: SelectedValueToOrderBy,
+        O: Ordering,
+    ]
+    WithOrderBySelectedValueClause<S, T, B, O>
+}
+
 /// A trait which allows ordering the results of a select statement by some
 /// value which is in the list of custom selected values of this statement in an
 /// ascending or descending order.
@@ -1,8 +1,13 @@
 use std::{fmt::Write, marker::PhantomData};
 
-use super::SqlStatement;
+use super::{
+    AndFilterCombinator, FilterCombinator, OrFilterCombinator, SelectFrom, SqlStatement,
+};
 use crate::{
-    sql::{FieldsConsListItem, ParameterBinder, SelectedValues, SqlBool, SqlExpression, UpdateSet},
+    sql::{
+        CombineSelectableTables, CombinedSelectableTables, FieldsConsListItem, ParameterBinder,
+        SelectableTables, SelectedValues, SqlBool, SqlExpression, UpdateSet,
+    },
     util::{TypedBool, TypedConsListNil, TypedFalse, TypedTrue},
     Table,
 };
@@ -16,6 +21,12 @@ pub trait UpdateStatement: Sized {
     /// The table that this statement updates.
     type UpdateTable: Table;
 
+    /// The tables whose columns can be referenced by this update statement.
+    ///
+    /// For update statements without a `FROM` clause, this is just the
+    /// updated table.
+    type SelectableTables: SelectableTables;
+
     /// Does this update statement have a `WHERE` clause?
     type HasWhereClause: TypedBool;
 
@@ -24,7 +35,7 @@ pub trait UpdateStatement: Sized {
 
     /// The update set which this update statement should perform on each row
     /// which matches the condition.
-    type UpdateSet: UpdateSet;
+    type UpdateSet: UpdateSet<Self::SelectableTables>;
 
     /// Writes the update set of this update statement.
     ///
@@ -37,8 +48,11 @@ pub trait UpdateStatement: Sized {
     where
         's: 'a;
 
-    /// Writes the `WHERE` clause of this update statement.
-    fn write_where_clause<'s, 'a>(
+    /// Writes the `FROM` clause of this update statement.
+    fn write_from_clause(&self, f: &mut String) -> std::fmt::Result;
+
+    /// Writes the condition of the `WHERE` clause of this update statement.
+    fn write_where_condition<'s, 'a>(
         &'s self,
         f: &mut String,
         parameter_binder: &mut ParameterBinder<'a>,
@@ -70,7 +84,11 @@ pub trait UpdateStatement: Sized {
             <Self::UpdateTable as Table>::TABLE_NAME
         )?;
         self.write_update_set(f, parameter_binder)?;
-        self.write_where_clause(f, parameter_binder)?;
+        self.write_from_clause(f)?;
+        if Self::HasWhereClause::VALUE {
+            write!(f, " WHERE ")?;
+            self.write_where_condition(f, parameter_binder)?;
+        }
         self.write_returning_clause(f, parameter_binder)
     }
 }
@@ -116,34 +134,84 @@ impl<T: Table> EmptyUpdateStatement<T> {
     /// macro.
     ///
     /// [`update_set!`]: gorm_macros::update_set
-    pub fn set<U: UpdateSet<UpdateTable = T>>(
+    pub fn set<U: UpdateSet<T, UpdateTable = T>>(
         self,
         update_set: U,
-    ) -> UpdateStatementWithUpdateSet<U> {
+    ) -> UpdateStatementWithUpdateSet<T, U> {
         UpdateStatementWithUpdateSet::new(update_set)
     }
+
+    /// Adds a `FROM` clause to this update statement, which allows its update
+    /// set and its condition to reference columns of other tables.
+    pub fn from<F: SelectFrom>(self, _from: F) -> EmptyUpdateFromStatement<T, F>
+    where
+        T: CombineSelectableTables<F::SelectableTables>,
+    {
+        EmptyUpdateFromStatement(PhantomData, PhantomData)
+    }
+}
+
+/// An empty sql update statement with a `FROM` clause, which doesn't have an
+/// update set yet. You can use the `set` function to add an update set to this
+/// statement.
+///
+/// This statement can be created by calling the [`EmptyUpdateStatement::from`]
+/// function.
+pub struct EmptyUpdateFromStatement<T: Table, F: SelectFrom>(PhantomData<T>, PhantomData<F>)
+where
+    T: CombineSelectableTables<F::SelectableTables>;
+impl<T: Table, F: SelectFrom> EmptyUpdateFromStatement<T, F>
+where
+    T: CombineSelectableTables<F::SelectableTables>,
+{
+    /// Adds an update set to this update statement which defines which columns
+    /// should be set and what their values should be. The new values may
+    /// reference columns of the tables in the `FROM` clause.
+    ///
+    /// To create an update set for this function, use the [`update_set!`]
+    /// macro.
+    ///
+    /// [`update_set!`]: gorm_macros::update_set
+    pub fn set<U: UpdateSet<CombinedSelectableTables<T, F::SelectableTables>, UpdateTable = T>>(
+        self,
+        update_set: U,
+    ) -> UpdateFromStatementWithUpdateSet<T, F, U> {
+        UpdateFromStatementWithUpdateSet::new(update_set)
+    }
 }
 
 /// An sql update statement which updates all rows in the table according to its
 /// update set.
 ///
+/// This statement can't be executed as is, to prevent accidentally updating all
+/// rows in the table. It must first either be filtered using the
+/// [`FilterUpdateStatement::filter`] function, or explicitly marked as updating
+/// all rows using the [`UpdateStatementAllRows::all_rows`] function.
+///
 /// This statement can be created by calling the [`EmptyUpdateStatement::set`]
 /// function.
-pub struct UpdateStatementWithUpdateSet<U: UpdateSet> {
+pub struct UpdateStatementWithUpdateSet<T: Table, U: UpdateSet<T, UpdateTable = T>> {
     update_set: U,
+    phantom: PhantomData<T>,
 }
-impl<U: UpdateSet> UpdateStatementWithUpdateSet<U> {
+impl<T: Table, U: UpdateSet<T, UpdateTable = T>> UpdateStatementWithUpdateSet<T, U> {
     pub fn new(update_set: U) -> Self {
-        Self { update_set }
+        Self {
+            update_set,
+            phantom: PhantomData,
+        }
     }
 }
 
-impl<U: UpdateSet> UpdateStatement for UpdateStatementWithUpdateSet<U> {
+impl<T: Table, U: UpdateSet<T, UpdateTable = T>> UpdateStatement
+    for UpdateStatementWithUpdateSet<T, U>
+{
     type HasReturningClause = TypedFalse;
     type HasWhereClause = TypedFalse;
     type OutputFields = TypedConsListNil;
+    type SelectableTables = T;
     type UpdateSet = U;
-    type UpdateTable = U::UpdateTable;
+    type UpdateTable = T;
 
     /// Writes the update set of this update statement.
     ///
@@ -159,8 +227,13 @@ impl<U: UpdateSet> UpdateStatement for UpdateStatementWithUpdateSet<U> {
         self.update_set.write_sql_string(f, parameter_binder)
     }
 
-    /// Writes the `WHERE` clause of this update statement.
-    fn write_where_clause<'s, 'a>(
+    /// Writes the `FROM` clause of this update statement.
+    fn write_from_clause(&self, _f: &mut String) -> std::fmt::Result {
+        Ok(())
+    }
+
+    /// Writes the condition of the `WHERE` clause of this update statement.
+    fn write_where_condition<'s, 'a>(
         &'s self,
         _f: &mut String,
         _parameter_binder: &mut ParameterBinder<'a>,
@@ -184,30 +257,118 @@ impl<U: UpdateSet> UpdateStatement for UpdateStatementWithUpdateSet<U> {
     }
 }
 
-impl<U: UpdateSet> SqlStatement for UpdateStatementWithUpdateSet<U> {
-    impl_sql_statement_for_update_statement! {}
+/// An sql update statement with a `FROM` clause which updates all rows in the
+/// table according to its update set.
+///
+/// Just like [`UpdateStatementWithUpdateSet`], this statement can't be executed
+/// before it is filtered or explicitly marked as updating all rows.
+///
+/// This statement can be created by calling the
+/// [`EmptyUpdateFromStatement::set`] function.
+pub struct UpdateFromStatementWithUpdateSet<T: Table, F: SelectFrom, U>
+where
+    T: CombineSelectableTables<F::SelectableTables>,
+    U: UpdateSet<CombinedSelectableTables<T, F::SelectableTables>, UpdateTable = T>,
+{
+    update_set: U,
+    phantom: PhantomData<(T, F)>,
+}
+impl<T: Table, F: SelectFrom, U> UpdateFromStatementWithUpdateSet<T, F, U>
+where
+    T: CombineSelectableTables<F::SelectableTables>,
+    U: UpdateSet<CombinedSelectableTables<T, F::SelectableTables>, UpdateTable = T>,
+{
+    pub fn new(update_set: U) -> Self {
+        Self {
+            update_set,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Table, F: SelectFrom, U> UpdateStatement for UpdateFromStatementWithUpdateSet<T, F, U>
+where
+    T: CombineSelectableTables<F::SelectableTables>,
+    U: UpdateSet<CombinedSelectableTables<T, F::SelectableTables>, UpdateTable = T>,
+{
+    type HasReturningClause = TypedFalse;
+    type HasWhereClause = TypedFalse;
+    type OutputFields = TypedConsListNil;
+    type SelectableTables = CombinedSelectableTables<T, F::SelectableTables>;
+    type UpdateSet = U;
+    type UpdateTable = T;
+
+    /// Writes the update set of this update statement.
+    ///
+    /// This is a list of comma seperated assignments to columns of the table.
+    fn write_update_set<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        self.update_set.write_sql_string(f, parameter_binder)
+    }
+
+    /// Writes the `FROM` clause of this update statement.
+    fn write_from_clause(&self, f: &mut String) -> std::fmt::Result {
+        write!(f, " FROM ")?;
+        F::write_sql_from_string(f)
+    }
+
+    /// Writes the condition of the `WHERE` clause of this update statement.
+    fn write_where_condition<'s, 'a>(
+        &'s self,
+        _f: &mut String,
+        _parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        Ok(())
+    }
+
+    /// Writes the `RETURNING` clause of this update statement.
+    fn write_returning_clause<'s, 'a>(
+        &'s self,
+        _f: &mut String,
+        _parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        Ok(())
+    }
 }
 
-/// A wrapper around an sql update statement which adds a `WHERE` clause to it.
+/// A wrapper around an sql update statement which adds a condition to its
+/// `WHERE` clause, combining it with the existing condition if there is one.
 ///
 /// This wrapper shouldn't be used directly, you should instead use the
-/// [`FilterUpdateStatement::filter`] function.
+/// [`FilterUpdateStatement::filter`] or
+/// [`OrFilterUpdateStatement::or_filter`] functions.
 pub struct UpdateWithWhereClause<
-    S: UpdateStatement<HasWhereClause = TypedFalse>,
-    C: SqlExpression<S::UpdateTable, SqlType = SqlBool>,
+    S: UpdateStatement,
+    C: SqlExpression<S::SelectableTables, SqlType = SqlBool>,
+    O: FilterCombinator,
 > {
     statement: S,
     condition: C,
+    phantom: PhantomData<O>,
 }
 
 impl<
-    S: UpdateStatement<HasWhereClause = TypedFalse>,
-    C: SqlExpression<S::UpdateTable, SqlType = SqlBool>,
-> UpdateStatement for UpdateWithWhereClause<S, C>
+    S: UpdateStatement,
+    C: SqlExpression<S::SelectableTables, SqlType = SqlBool>,
+    O: FilterCombinator,
+> UpdateStatement for UpdateWithWhereClause<S, C, O>
 {
     type HasReturningClause = S::HasReturningClause;
     type HasWhereClause = TypedTrue;
     type OutputFields = S::OutputFields;
+    type SelectableTables = S::SelectableTables;
     type UpdateSet = S::UpdateSet;
     type UpdateTable = S::UpdateTable;
 
@@ -225,8 +386,13 @@ impl<
         self.statement.write_update_set(f, parameter_binder)
     }
 
-    /// Writes the `WHERE` clause of this update statement.
-    fn write_where_clause<'s, 'a>(
+    /// Writes the `FROM` clause of this update statement.
+    fn write_from_clause(&self, f: &mut String) -> std::fmt::Result {
+        self.statement.write_from_clause(f)
+    }
+
+    /// Writes the condition of the `WHERE` clause of this update statement.
+    fn write_where_condition<'s, 'a>(
         &'s self,
         f: &mut String,
         parameter_binder: &mut ParameterBinder<'a>,
@@ -234,8 +400,15 @@ impl<
     where
         's: 'a,
     {
-        write!(f, " WHERE ")?;
-        self.condition.write_sql_string(f, parameter_binder)
+        if S::HasWhereClause::VALUE {
+            write!(f, "(")?;
+            self.statement.write_where_condition(f, parameter_binder)?;
+            write!(f, ") {} (", O::OPERATOR_STR)?;
+            self.condition.write_sql_string(f, parameter_binder)?;
+            write!(f, ")")
+        } else {
+            self.condition.write_sql_string(f, parameter_binder)
+        }
     }
 
     /// Writes the `RETURNING` clause of this update statement.
@@ -252,30 +425,156 @@ impl<
 }
 
 impl<
-    S: UpdateStatement<HasWhereClause = TypedFalse> + 'static,
-    C: SqlExpression<S::UpdateTable, SqlType = SqlBool> + 'static,
-> SqlStatement for UpdateWithWhereClause<S, C>
+    S: UpdateStatement + 'static,
+    C: SqlExpression<S::SelectableTables, SqlType = SqlBool> + 'static,
+    O: FilterCombinator + 'static,
+> SqlStatement for UpdateWithWhereClause<S, C, O>
 {
     impl_sql_statement_for_update_statement! {}
 }
 
+impl_display_and_debug_for_sql_statement! {
+    [
+        S: UpdateStatement,
+        C: SqlExpression<S::SelectableTables, SqlType = SqlBool>,
+        O: FilterCombinator,
+    ]
+    UpdateWithWhereClause<S, C, O>
+}
+
 /// A trait which allows filtering an update statement so that it only updates
 /// records matching some condition.
-pub trait FilterUpdateStatement: UpdateStatement<HasWhereClause = TypedFalse> {
+pub trait FilterUpdateStatement: UpdateStatement {
     /// Filters this update statement, so that it only updates records which
     /// match the given condition.
-    fn filter<C: SqlExpression<Self::UpdateTable, SqlType = SqlBool>>(
+    ///
+    /// If this statement is already filtered, the given condition is combined
+    /// with the existing one using the `AND` operator.
+    fn filter<C: SqlExpression<Self::SelectableTables, SqlType = SqlBool>>(
         self,
         condition: C,
-    ) -> UpdateWithWhereClause<Self, C> {
+    ) -> UpdateWithWhereClause<Self, C, AndFilterCombinator> {
         UpdateWithWhereClause {
             statement: self,
             condition,
+            phantom: PhantomData,
         }
     }
 }
 
-impl<T: UpdateStatement<HasWhereClause = TypedFalse>> FilterUpdateStatement for T {}
+impl<T: UpdateStatement> FilterUpdateStatement for T {}
+
+/// A trait which allows extending the filter of an already filtered update
+/// statement so that it also updates records matching some other condition.
+pub trait OrFilterUpdateStatement: UpdateStatement<HasWhereClause = TypedTrue> {
+    /// Extends the filter of this update statement, so that it also updates
+    /// records which match the given condition, by combining it with the
+    /// existing condition using the `OR` operator.
+    fn or_filter<C: SqlExpression<Self::SelectableTables, SqlType = SqlBool>>(
+        self,
+        condition: C,
+    ) -> UpdateWithWhereClause<Self, C, OrFilterCombinator> {
+        UpdateWithWhereClause {
+            statement: self,
+            condition,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: UpdateStatement<HasWhereClause = TypedTrue>> OrFilterUpdateStatement for T {}
+
+/// A wrapper around an sql update statement which explicitly marks it as
+/// updating all rows in the table, which allows executing it without filtering
+/// it.
+///
+/// This wrapper shouldn't be used directly, you should instead use the
+/// [`UpdateStatementAllRows::all_rows`] function.
+pub struct UpdateWithAllRows<S: UpdateStatement<HasWhereClause = TypedFalse>> {
+    statement: S,
+}
+
+impl<S: UpdateStatement<HasWhereClause = TypedFalse>> UpdateStatement for UpdateWithAllRows<S> {
+    type HasReturningClause = S::HasReturningClause;
+    type HasWhereClause = TypedTrue;
+    type OutputFields = S::OutputFields;
+    type SelectableTables = S::SelectableTables;
+    type UpdateSet = S::UpdateSet;
+    type UpdateTable = S::UpdateTable;
+
+    /// Writes the update set of this update statement.
+    ///
+    /// This is a list of comma seperated assignments to columns of the table.
+    fn write_update_set<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        self.statement.write_update_set(f, parameter_binder)
+    }
+
+    /// Writes the `FROM` clause of this update statement.
+    fn write_from_clause(&self, f: &mut String) -> std::fmt::Result {
+        self.statement.write_from_clause(f)
+    }
+
+    /// Writes the condition of the `WHERE` clause of this update statement.
+    fn write_where_condition<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        _parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        // the condition of a statement which affects all rows is always true, so
+        // that it can still be combined with other conditions.
+        write!(f, "TRUE")
+    }
+
+    /// Writes the `RETURNING` clause of this update statement.
+    fn write_returning_clause<'s, 'a>(
+        &'s self,
+        f: &mut String,
+        parameter_binder: &mut ParameterBinder<'a>,
+    ) -> std::fmt::Result
+    where
+        's: 'a,
+    {
+        self.statement.write_returning_clause(f, parameter_binder)
+    }
+}
+
+impl<S: UpdateStatement<HasWhereClause = TypedFalse> + 'static> SqlStatement
+    for UpdateWithAllRows<S>
+{
+    impl_sql_statement_for_update_statement! {}
+}
+
+impl_display_and_debug_for_sql_statement! {
+    [
+        S: UpdateStatement<HasWhereClause = TypedFalse>,
+    ]
+    UpdateWithAllRows<S>
+}
+
+/// A trait which allows explicitly marking an update statement as updating all
+/// rows in the table.
+pub trait UpdateStatementAllRows: UpdateStatement<HasWhereClause = TypedFalse> {
+    /// Marks this update statement as updating all rows in the table.
+    ///
+    /// Update statements which aren't filtered can't be executed unless
+    /// this function is called, to prevent accidentally updating all rows in
+    /// the table.
+    fn all_rows(self) -> UpdateWithAllRows<Self> {
+        UpdateWithAllRows { statement: self }
+    }
+}
+
+impl<T: UpdateStatement<HasWhereClause = TypedFalse>> UpdateStatementAllRows for T {}
 
 /// A wrapper around an sql update statement which adds a `RETURNING` clause to
 /// it.
@@ -284,18 +583,19 @@ impl<T: UpdateStatement<HasWhereClause = TypedFalse>> FilterUpdateStatement for
 /// [`UpdateStatementReturning::returning`] function.
 pub struct UpdateWithReturningClause<
     S: UpdateStatement<HasReturningClause = TypedFalse>,
-    R: SelectedValues<S::UpdateTable>,
+    R: SelectedValues<S::SelectableTables>,
 > {
     statement: S,
     returning: R,
 }
 
-impl<S: UpdateStatement<HasReturningClause = TypedFalse>, R: SelectedValues<S::UpdateTable>>
+impl<S: UpdateStatement<HasReturningClause = TypedFalse>, R: SelectedValues<S::SelectableTables>>
     UpdateStatement for UpdateWithReturningClause<S, R>
 {
     type HasReturningClause = TypedTrue;
     type HasWhereClause = S::HasWhereClause;
     type OutputFields = R::Fields;
+    type SelectableTables = S::SelectableTables;
     type UpdateSet = S::UpdateSet;
     type UpdateTable = S::UpdateTable;
 
@@ -313,8 +613,13 @@ impl<S: UpdateStatement<HasReturningClause = TypedFalse>, R: SelectedValues<S::U
         self.statement.write_update_set(f, parameter_binder)
     }
 
-    /// Writes the `WHERE` clause of this update statement.
-    fn write_where_clause<'s, 'a>(
+    /// Writes the `FROM` clause of this update statement.
+    fn write_from_clause(&self, f: &mut String) -> std::fmt::Result {
+        self.statement.write_from_clause(f)
+    }
+
+    /// Writes the condition of the `WHERE` clause of this update statement.
+    fn write_where_condition<'s, 'a>(
         &'s self,
         f: &mut String,
         parameter_binder: &mut ParameterBinder<'a>,
@@ -322,7 +627,7 @@ impl<S: UpdateStatement<HasReturningClause = TypedFalse>, R: SelectedValues<S::U
     where
         's: 'a,
     {
-        self.statement.write_where_clause(f, parameter_binder)
+        self.statement.write_where_condition(f, parameter_binder)
     }
 
     /// Writes the `RETURNING` clause of this update statement.
@@ -339,12 +644,22 @@ impl<S: UpdateStatement<HasReturningClause = TypedFalse>, R: SelectedValues<S::U
     }
 }
 
-impl<S: UpdateStatement<HasReturningClause = TypedFalse>, R: SelectedValues<S::UpdateTable>>
-    SqlStatement for UpdateWithReturningClause<S, R>
+impl<
+    S: UpdateStatement<HasReturningClause = TypedFalse, HasWhereClause = TypedTrue>,
+    R: SelectedValues<S::SelectableTables>,
+> SqlStatement for UpdateWithReturningClause<S, R>
 {
     impl_sql_statement_for_update_statement! {}
 }
 
+impl_display_and_debug_for_sql_statement! {
+    [
+        S: UpdateStatement<HasReturningClause = TypedFalse>,
+        R: SelectedValues<S::SelectableTables>,
+    ]
+    UpdateWithReturningClause<S, R>
+}
+
 /// A trait which allows returning some values from the records updated by some
 /// update statement.
 pub trait UpdateStatementReturning: UpdateStatement<HasReturningClause = TypedFalse> {
@@ -353,7 +668,7 @@ pub trait UpdateStatementReturning: UpdateStatement<HasReturningClause = TypedFa
     /// [`returning!`] macro.
     ///
     /// [`returning!`]: crate::returning
-    fn returning<R: SelectedValues<Self::UpdateTable>>(
+    fn returning<R: SelectedValues<Self::SelectableTables>>(
         self,
         returning: R,
     ) -> UpdateWithReturningClause<Self, R> {
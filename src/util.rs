@@ -1,6 +1,6 @@
 //! Utilities that are not directly related to the purpose of this crate.
 
-use crate::sql::{FieldNameCharsConsListItem, FieldsConsListItem};
+use crate::sql::{FieldNameCharsConsListItem, FieldsConsListItem, SqlOption, SqlType};
 
 /// A marker trait for marking 2 types that are not the same type.
 /// Used for generic constraints where 2 types must not be equal.
@@ -27,10 +27,34 @@ pub struct TypedTrue;
 pub trait TypedBool {
     /// The boolean value of the type.
     const VALUE: bool;
+
+    /// The result of a logical or between this boolean and the boolean `B`.
+    type Or<B: TypedBool>: TypedBool;
+
+    /// The non-nullable sql type `T` if this boolean is false, or `T` wrapped
+    /// in an `SqlOption` if it is true.
+    ///
+    /// This is used to propagate the nullability of the arguments of an sql
+    /// expression to its result.
+    type NullableSqlTypeIf<T: SqlType>: SqlType
+    where
+        (T::IsNull, TypedFalse): TypesEqual;
 }
 impl TypedBool for TypedFalse {
+    type NullableSqlTypeIf<T: SqlType>
+        = T
+    where
+        (T::IsNull, TypedFalse): TypesEqual;
+    type Or<B: TypedBool> = B;
+
     const VALUE: bool = false;
 }
 impl TypedBool for TypedTrue {
+    type NullableSqlTypeIf<T: SqlType>
+        = SqlOption<T>
+    where
+        (T::IsNull, TypedFalse): TypesEqual;
+    type Or<B: TypedBool> = TypedTrue;
+
     const VALUE: bool = true;
 }
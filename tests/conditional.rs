@@ -0,0 +1,58 @@
+use gorm::{
+    select_values,
+    sql::{case_when, coalesce, nullif, OrderableSqlExpression},
+    statements::{SelectFrom, SelectValues, SqlStatement},
+    Table,
+};
+
+#[derive(Debug, Table)]
+pub struct Person {
+    pub id: i32,
+    pub name: String,
+    pub age: i32,
+    pub nickname: Option<String>,
+}
+
+#[test]
+fn case_when_casts_values() {
+    let age_group = case_when(person::age.greater_than(65), 2i32)
+        .when(person::age.greater_than(18), 1i32)
+        .otherwise(0i32);
+    let statement = person::table
+        .find()
+        .select(select_values!(age_group as age_group));
+
+    assert_eq!(
+        statement.to_debug_sql(),
+        "SELECT CASE WHEN \"person\".\"age\">65 THEN CAST(2 AS integer) WHEN \
+         \"person\".\"age\">18 THEN CAST(1 AS integer) ELSE CAST(0 AS integer) END as \
+         age_group FROM \"person\""
+    );
+}
+
+#[test]
+fn coalesce_casts_arguments() {
+    let nickname = coalesce(person::nickname, None::<String>).or_else("unknown");
+    let statement = person::table
+        .find()
+        .select(select_values!(nickname as nickname));
+
+    assert_eq!(
+        statement.to_debug_sql(),
+        "SELECT COALESCE(CAST(\"person\".\"nickname\" AS text), CAST(NULL AS text), \
+         CAST('unknown' AS text)) as nickname FROM \"person\""
+    );
+}
+
+#[test]
+fn nullif_casts_arguments() {
+    let statement = person::table
+        .find()
+        .select(select_values!(nullif(person::age, 0i32) as age));
+
+    assert_eq!(
+        statement.to_debug_sql(),
+        "SELECT NULLIF(CAST(\"person\".\"age\" AS integer), CAST(0 AS integer)) as age FROM \
+         \"person\""
+    );
+}
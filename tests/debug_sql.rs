@@ -0,0 +1,111 @@
+use std::net::{IpAddr, Ipv4Addr};
+
+use gorm::{
+    sql,
+    sql::{
+        BooleanOrableSqlExpression, Interval, NullableSqlExpression, OrderableSqlExpression,
+        SqlBool, SqlExpression,
+    },
+    statements::{Filter, SelectFrom, SqlStatement},
+    Table,
+};
+
+#[derive(Debug, Table)]
+pub struct Thing {
+    pub id: i32,
+    pub name: String,
+    pub nickname: Option<String>,
+    pub score: f64,
+    pub data: Vec<u8>,
+    pub duration: Interval,
+    pub nums: Vec<i32>,
+}
+
+#[test]
+fn text_with_quotes() {
+    let statement = thing::table.find().filter(thing::name.equals("it's"));
+
+    assert_eq!(
+        statement.to_debug_sql(),
+        "SELECT * FROM \"thing\" WHERE \"thing\".\"name\"='it''s'"
+    );
+}
+
+#[test]
+fn null() {
+    let statement = thing::table
+        .find()
+        .filter(thing::nickname.is_not_distinct_from(None::<String>));
+
+    assert_eq!(
+        statement.to_debug_sql(),
+        "SELECT * FROM \"thing\" WHERE \"thing\".\"nickname\" IS NOT DISTINCT FROM NULL"
+    );
+}
+
+#[test]
+fn special_floats() {
+    let statement = thing::table.find().filter(
+        thing::score
+            .equals(f64::NAN)
+            .or(thing::score.lower_than(f64::INFINITY))
+            .or(thing::score.greater_than(f64::NEG_INFINITY))
+            .or(thing::score.equals(1.5)),
+    );
+
+    assert_eq!(
+        statement.to_debug_sql(),
+        "SELECT * FROM \"thing\" WHERE (((\"thing\".\"score\"='NaN') OR \
+         (\"thing\".\"score\"<'Infinity')) OR (\"thing\".\"score\">'-Infinity')) OR \
+         (\"thing\".\"score\"=1.5)"
+    );
+}
+
+#[test]
+fn bytea() {
+    let statement = thing::table
+        .find()
+        .filter(thing::data.equals(vec![0xdeu8, 0xad, 0x01]));
+
+    assert_eq!(
+        statement.to_debug_sql(),
+        "SELECT * FROM \"thing\" WHERE \"thing\".\"data\"='\\xdead01'"
+    );
+}
+
+#[test]
+fn interval() {
+    let statement = thing::table
+        .find()
+        .filter(thing::duration.equals(Interval::new(1, 2, 3)));
+
+    assert_eq!(
+        statement.to_debug_sql(),
+        "SELECT * FROM \"thing\" WHERE \"thing\".\"duration\"='1 mons 2 days 3 microseconds'"
+    );
+}
+
+#[test]
+fn array() {
+    let statement = thing::table
+        .find()
+        .filter(thing::nums.equals(vec![1, 2, 3]));
+
+    assert_eq!(
+        statement.to_debug_sql(),
+        "SELECT * FROM \"thing\" WHERE \"thing\".\"nums\"=ARRAY[1,2,3]::int4[]"
+    );
+}
+
+#[test]
+fn debug_fallback() {
+    let address = IpAddr::V4(Ipv4Addr::new(10, 1, 2, 3));
+    let statement = thing::table
+        .find()
+        .filter(sql!(SqlBool, "{} << inet '10.0.0.0/8'", address));
+
+    assert_eq!(
+        statement.to_debug_sql(),
+        "SELECT * FROM \"thing\" WHERE ('10.1.2.3' << inet '10.0.0.0/8')"
+    );
+}
@@ -0,0 +1,130 @@
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use deadpool_postgres::tokio_postgres::types::FromSqlOwned;
+use gorm::{
+    execution::{ExecuteResult, SqlStatementExecutor},
+    sql::{
+        FieldNameCharsConsListItem, FieldsConsListCons, FromQueryResult, OrderableSqlExpression,
+    },
+    statements::{Filter, SelectFrom, SqlStatement},
+    util::{TypedConsListNil, TypesNotEqual},
+    Error, Result, Table,
+};
+
+#[derive(Debug, Table)]
+pub struct Person {
+    pub id: i32,
+    pub name: String,
+    pub age: i32,
+}
+
+/// An executor which only implements the required methods of
+/// [`SqlStatementExecutor`], and records the statements passed to it instead
+/// of executing them.
+#[derive(Default)]
+struct RecordingExecutor {
+    statements: Mutex<Vec<String>>,
+}
+impl RecordingExecutor {
+    fn record<T>(&self, statement: impl SqlStatement) -> Result<T> {
+        self.statements
+            .lock()
+            .unwrap()
+            .push(statement.to_debug_sql());
+        Err(Error::NoRecords)
+    }
+}
+
+#[async_trait]
+impl SqlStatementExecutor for RecordingExecutor {
+    async fn execute(&self, statement: impl SqlStatement + Send) -> Result<ExecuteResult> {
+        self.record(statement)
+    }
+
+    async fn load_one<O: FromQueryResult + Send, S: SqlStatement + Send>(
+        &self,
+        statement: S,
+    ) -> Result<O>
+    where
+        (S::OutputFields, TypedConsListNil): TypesNotEqual,
+    {
+        self.record(statement)
+    }
+
+    async fn load_one_value<
+        FieldName: FieldNameCharsConsListItem,
+        FieldType: FromSqlOwned + Send,
+        S: SqlStatement<OutputFields = FieldsConsListCons<FieldName, FieldType, TypedConsListNil>>
+            + Send,
+    >(
+        &self,
+        statement: S,
+    ) -> Result<FieldType> {
+        self.record(statement)
+    }
+
+    async fn load_optional<O: FromQueryResult + Send, S: SqlStatement + Send>(
+        &self,
+        statement: S,
+    ) -> Result<Option<O>>
+    where
+        (S::OutputFields, TypedConsListNil): TypesNotEqual,
+    {
+        self.record(statement)
+    }
+
+    async fn load_optional_value<
+        FieldName: FieldNameCharsConsListItem,
+        FieldType: FromSqlOwned + Send,
+        S: SqlStatement<OutputFields = FieldsConsListCons<FieldName, FieldType, TypedConsListNil>>
+            + Send,
+    >(
+        &self,
+        statement: S,
+    ) -> Result<Option<FieldType>> {
+        self.record(statement)
+    }
+
+    async fn load_all<O: FromQueryResult + Send, S: SqlStatement + Send>(
+        &self,
+        statement: S,
+    ) -> Result<Vec<O>>
+    where
+        (S::OutputFields, TypedConsListNil): TypesNotEqual,
+    {
+        self.record(statement)
+    }
+
+    async fn load_all_values<
+        FieldName: FieldNameCharsConsListItem,
+        FieldType: FromSqlOwned + Send,
+        S: SqlStatement<OutputFields = FieldsConsListCons<FieldName, FieldType, TypedConsListNil>>
+            + Send,
+    >(
+        &self,
+        statement: S,
+    ) -> Result<Vec<FieldType>> {
+        self.record(statement)
+    }
+}
+
+#[tokio::test]
+async fn default_explain() {
+    let executor = RecordingExecutor::default();
+
+    let _ = executor
+        .explain(person::table.find().filter(person::age.greater_than(18)))
+        .await;
+    let _ = executor
+        .explain_analyze(person::table.find().filter(person::age.greater_than(18)))
+        .await;
+
+    assert_eq!(
+        *executor.statements.lock().unwrap(),
+        [
+            "EXPLAIN (FORMAT JSON) SELECT * FROM \"person\" WHERE \"person\".\"age\">18",
+            "EXPLAIN (ANALYZE, FORMAT JSON) SELECT * FROM \"person\" WHERE \"person\".\"age\">18",
+        ]
+    );
+}
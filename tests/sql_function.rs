@@ -0,0 +1,74 @@
+use gorm::{
+    select_values,
+    sql::{AggregateFilter, OrderableSqlExpression, SqlF32, SqlI32, SqlOption, SqlText},
+    sql_function,
+    statements::{Filter, SelectFrom, SelectValues, SqlStatement},
+    Table,
+};
+
+#[derive(Debug, Table)]
+pub struct Person {
+    pub id: i32,
+    pub name: String,
+    pub age: i32,
+}
+
+sql_function! {
+    /// Returns a number which indicates how similar the two strings are.
+    pub fn similarity(lhs: L: SqlText, rhs: R: SqlText) -> SqlF32 {
+        Similarity, "similarity"
+    }
+}
+
+sql_function! {
+    /// Returns the bitwise xor of all values returned from the query.
+    pub aggregate fn bit_xor(value: V: SqlI32) -> SqlOption<SqlI32> {
+        BitXor, "BIT_XOR"
+    }
+}
+
+sql_function! {
+    /// Returns the amount of values returned from the query, which is never null.
+    pub aggregate fn count_values(value: V: SqlI32) -> SqlI32 {
+        CountValues, "COUNT"
+    }
+}
+
+#[test]
+fn function() {
+    let statement = person::table
+        .find()
+        .filter(similarity(person::name, "James").greater_than(0.5f32));
+
+    assert_eq!(
+        statement.to_debug_sql(),
+        "SELECT * FROM \"person\" WHERE similarity(\"person\".\"name\", 'James')>0.5"
+    );
+}
+
+#[test]
+fn aggregate_function() {
+    let statement = person::table
+        .find()
+        .select(select_values!(bit_xor(person::age) as age_xor));
+
+    assert_eq!(
+        statement.to_debug_sql(),
+        "SELECT BIT_XOR(\"person\".\"age\") as age_xor FROM \"person\""
+    );
+}
+
+#[test]
+fn aggregate_function_with_filter() {
+    let statement = person::table.find().select(select_values!(
+        bit_xor(person::age).filter(person::age.greater_than(18)) as age_xor,
+        count_values(person::age).filter(person::age.greater_than(18)) as adults
+    ));
+
+    assert_eq!(
+        statement.to_debug_sql(),
+        "SELECT BIT_XOR(\"person\".\"age\") FILTER (WHERE \"person\".\"age\">18) as age_xor,\
+         COUNT(\"person\".\"age\") FILTER (WHERE \"person\".\"age\">18) as adults FROM \
+         \"person\""
+    );
+}